@@ -0,0 +1,156 @@
+//! Example on how a deployed `counter-proxy` program keeps the counter's state across an upgrade.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It points the proxy at a deployed `counter` program, appointing the wallet as the counter's
+//! owner in the same transaction, sets the number through the proxy, then upgrades to a deployed
+//! `counter-v2` program and checks the `Upgraded` event, that the number is unchanged and that
+//! the methods only v2 has work, as do those it inherits, such as the transient number. Upgrades
+//! by anyone but the admin revert.
+//! The proxy must be built with `COUNTER_PROXY_DEPLOYER` set to the wallet's address, so the
+//! example can initialize it.
+
+use ethers::{
+    contract::parse_log,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes},
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Deployed `counter` program address.
+const V1_PROGRAM_ADDRESS: &str = "V1_ADDR";
+
+/// Deployed `counter-v2` program address.
+const V2_PROGRAM_ADDRESS: &str = "V2_ADDR";
+
+/// The minimum delay of the counter's timelock, in seconds.
+const MIN_DELAY: u64 = 5;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    let v1_address = std::env::var(V1_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", V1_PROGRAM_ADDRESS))?;
+    let v2_address = std::env::var(V2_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", V2_PROGRAM_ADDRESS))?;
+    abigen!(
+        CounterProxy,
+        r#"[
+            event Upgraded(address indexed implementation)
+            function initialize(address implementation, bytes data) external
+            function upgradeTo(address implementation) external
+            function implementation() external view returns (address)
+            function admin() external view returns (address)
+        ]"#
+    );
+    abigen!(
+        CounterV2,
+        r#"[
+            function number() external view returns (uint256)
+            function initializeOwner(address owner, uint256 minDelay) external
+            function owner() external view returns (address)
+            function getMinDelay() external view returns (uint256)
+            function setNumber(uint256 number) external
+            function increment() external
+            function decrement() external
+            function transientIncrement() external returns (uint256)
+            function transientNumber() external view returns (uint256)
+            function version() external view returns (uint256)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let v1: Address = v1_address.parse()?;
+    let v2: Address = v2_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let proxy = CounterProxy::new(address, client.clone());
+    // `counter-v2` inherits every method of v1, so its ABI serves for both versions behind the
+    // proxy.
+    let counter = CounterV2::new(address, client);
+
+    if proxy.admin().call().await?.is_zero() {
+        let init = counter
+            .initialize_owner(wallet.address(), U256::from(MIN_DELAY))
+            .calldata()
+            .ok_or_else(|| eyre!("No calldata"))?;
+        proxy.initialize(v1, init).send().await?.await?;
+    } else {
+        proxy.upgrade_to(v1).send().await?.await?;
+    }
+    assert_eq!(wallet.address(), proxy.admin().call().await?);
+    assert_eq!(v1, proxy.implementation().call().await?);
+    assert_eq!(wallet.address(), counter.owner().call().await?);
+    assert!(!counter.get_min_delay().call().await?.is_zero());
+    assert!(proxy.initialize(v2, Bytes::new()).call().await.is_err());
+
+    let value = counter.number().call().await? + U256::from(41);
+    counter.set_number(value).send().await?.await?;
+    counter.increment().send().await?.await?;
+    let incremented = value + U256::one();
+    assert_eq!(incremented, counter.number().call().await?);
+    assert!(counter.version().call().await.is_err());
+    println!("Counter v1 behind the proxy holds {incremented}");
+
+    // Only the admin may upgrade.
+    let stranger = Address::repeat_byte(0x11);
+    assert!(proxy.upgrade_to(v2).from(stranger).call().await.is_err());
+
+    let receipt = proxy
+        .upgrade_to(v2)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let upgraded: UpgradedFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!(v2, upgraded.implementation);
+    assert_eq!(v2, proxy.implementation().call().await?);
+    println!("Upgraded the proxy to {v2:?}");
+
+    assert_eq!(incremented, counter.number().call().await?);
+    assert_eq!(U256::from(2), counter.version().call().await?);
+    counter.decrement().send().await?.await?;
+    assert_eq!(value, counter.number().call().await?);
+    println!("Counter v2 behind the proxy kept the number and can decrement it");
+
+    assert_eq!(U256::one(), counter.transient_increment().call().await?);
+    counter.transient_increment().send().await?.await?;
+    assert_eq!(U256::zero(), counter.transient_number().call().await?);
+    println!("Counter v2 behind the proxy still has the transient number of v1");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
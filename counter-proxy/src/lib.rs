@@ -0,0 +1,207 @@
+//!
+//! Stylus Hello World
+//!
+//! The following contract is an upgradeable proxy for the `counter` program. Calls it doesn't
+//! recognize are delegated to the implementation, so the counter's state lives in the proxy and
+//! survives an upgrade to `counter-v2`.
+//!
+//! ```
+//! contract CounterProxy {
+//!     event Upgraded(address indexed implementation);
+//!     address immutable deployer = msg.sender;
+//!     function initialize(address implementation, bytes memory data) public {
+//!         require(msg.sender == deployer && admin() == address(0));
+//!         _setAdmin(msg.sender);
+//!         _upgradeTo(implementation);
+//!         if (data.length > 0) Address.functionDelegateCall(implementation, data);
+//!     }
+//!     function upgradeTo(address implementation) public {
+//!         require(msg.sender == admin());
+//!         _upgradeTo(implementation);
+//!     }
+//!     fallback() external {
+//!         _delegate(implementation());
+//!     }
+//! }
+//! ```
+//!
+//! The implementation and admin addresses are kept in the ERC-1967 slots, away from the slots
+//! the counter's `sol_storage!` fields are placed in.
+//!
+//! Stylus programs have no constructor, so deploying and initializing take two transactions.
+//! Only the deployer can initialize in between, so nobody can front-run them to become the admin.
+//! As there are no immutables either, the deployer's address is compiled in from the
+//! `COUNTER_PROXY_DEPLOYER` environment variable, which must be set to an address when building
+//! the program.
+//! `initialize` also runs its `data` against the implementation, so the counter's owner can be
+//! appointed with `initializeOwner` in the same transaction, before anyone else can claim it.
+//!
+//! The program is ABI-equivalent with Solidity, which means you can call it from both Solidity and Rust.
+//! To do this, run `cargo stylus export-abi`.
+//!
+//! Note: this code is a template-only and has not been audited.
+//!
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+/// Use an efficient WASM allocator.
+#[global_allocator]
+static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolError};
+/// Import items from the SDK. The prelude contains common traits and macros.
+use stylus_sdk::{
+    abi::{Bytes, Router},
+    alloy_primitives::{uint, Address, U256},
+    call::{delegate_call, Call},
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageCache},
+};
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+const IMPLEMENTATION_SLOT: U256 =
+    uint!(0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc_U256);
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)`
+const ADMIN_SLOT: U256 =
+    uint!(0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103_U256);
+
+/// The proxy's own state, in the ERC-1967 slots rather than from slot 0 as `sol_storage!` would
+/// place it.
+pub struct CounterProxy {
+    implementation: StorageAddress,
+    admin: StorageAddress,
+}
+
+impl CounterProxy {
+    fn load() -> Self {
+        // Safety: the ERC-1967 slots are only accessed through these fields.
+        unsafe {
+            Self {
+                implementation: StorageAddress::new(IMPLEMENTATION_SLOT, 0),
+                admin: StorageAddress::new(ADMIN_SLOT, 0),
+            }
+        }
+    }
+
+    fn set_implementation(&mut self, implementation: Address) {
+        self.implementation.set(implementation);
+        evm::log(Upgraded { implementation });
+    }
+}
+
+/// The address allowed to initialize the proxy, as set at build time. A malformed value fails
+/// the build rather than every `initialize`.
+const DEPLOYER: Address = parse_address(env!("COUNTER_PROXY_DEPLOYER"));
+
+/// Parses a `0x`-prefixed hex address in a const context, panicking if it isn't one.
+const fn parse_address(s: &str) -> Address {
+    let s = s.as_bytes();
+    if s.len() != 42 || s[0] != b'0' || (s[1] != b'x' && s[1] != b'X') {
+        panic!("COUNTER_PROXY_DEPLOYER must be a 0x-prefixed 20-byte hex address");
+    }
+    let mut bytes = [0u8; 20];
+    let mut i = 0;
+    while i < 20 {
+        bytes[i] = (hex_digit(s[2 + 2 * i]) << 4) | hex_digit(s[3 + 2 * i]);
+        i += 1;
+    }
+    Address::new(bytes)
+}
+
+/// The value of a hex digit, panicking if it isn't one.
+const fn hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("COUNTER_PROXY_DEPLOYER must be a 0x-prefixed 20-byte hex address"),
+    }
+}
+
+/// `CounterProxy` is the only storage the program itself declares.
+unsafe impl TopLevelStorage for CounterProxy {}
+
+sol! {
+    event Upgraded(address indexed implementation);
+
+    error AlreadyInitialized();
+    error Unauthorized(address caller);
+    error NotInitialized();
+}
+
+/// Routes the proxy's own methods to `CounterProxy` and delegates every other call, with the
+/// calldata unchanged, to the implementation.
+#[entrypoint]
+fn user_main(input: Vec<u8>) -> ArbResult {
+    let mut proxy = CounterProxy::load();
+    if let Some(selector) = input.get(..4) {
+        let selector = u32::from_be_bytes(selector.try_into().unwrap());
+        if let Some(result) = CounterProxy::route(&mut proxy, selector, &input[4..]) {
+            return result;
+        }
+    }
+    let implementation = proxy.implementation.get();
+    if implementation.is_zero() {
+        return Err(NotInitialized {}.abi_encode());
+    }
+    // Safety: the implementation is trusted with the proxy's storage, which is the point.
+    unsafe { delegate_call(Call::new_in(&mut proxy), implementation, &input) }.map_err(Into::into)
+}
+
+/// Prints the Solidity ABI, which `#[entrypoint]` on a function doesn't generate.
+#[cfg(feature = "export-abi")]
+pub fn print_abi(license: &str, pragma: &str) {
+    stylus_sdk::abi::export::print_abi::<CounterProxy>(license, pragma);
+}
+
+/// Declare that `CounterProxy` is a contract with the following external methods.
+#[external]
+impl CounterProxy {
+    /// Makes the caller the admin, points the proxy at `implementation` and delegates `data` to
+    /// it as an initializer unless empty. Only the deployer can, and only once.
+    pub fn initialize(&mut self, implementation: Address, data: Bytes) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if caller != DEPLOYER {
+            return Err(Unauthorized { caller }.abi_encode());
+        }
+        if !self.admin.get().is_zero() {
+            return Err(AlreadyInitialized {}.abi_encode());
+        }
+        self.admin.set(caller);
+        self.set_implementation(implementation);
+        if !data.is_empty() {
+            // The initializer reads the slots just written, such as the admin `initializeOwner`
+            // checks, so they have to reach the state before it runs. Nothing here reads them
+            // again, so the cache can be dropped along with whatever the initializer changes.
+            StorageCache::clear();
+            // Safety: the implementation is trusted with the proxy's storage, as in `user_main`.
+            unsafe { delegate_call(Call::new_in(self), implementation, &data) }?;
+        }
+        Ok(())
+    }
+
+    /// Points the proxy at a new implementation. Only the admin can upgrade.
+    pub fn upgrade_to(&mut self, implementation: Address) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.admin.get() {
+            return Err(Unauthorized { caller }.abi_encode());
+        }
+        self.set_implementation(implementation);
+        Ok(())
+    }
+
+    /// Gets the address calls are delegated to.
+    pub fn implementation(&self) -> Address {
+        self.implementation.get()
+    }
+
+    /// Gets the address allowed to upgrade the proxy.
+    pub fn admin(&self) -> Address {
+        self.admin.get()
+    }
+}
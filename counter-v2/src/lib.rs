@@ -0,0 +1,86 @@
+//!
+//! Stylus Hello World
+//!
+//! The following contract is the second version of the `counter` program, to be upgraded to
+//! behind `counter-proxy`. It inherits every method of `counter`'s `Counter`, whose storage it
+//! starts with at the same ERC-7201 namespace, and adds `decrement` and `version`. Like `counter`,
+//! it is a UUPS implementation, so the proxy can be upgraded again from v2.
+//!
+//! ```
+//! contract CounterV2 is Counter {
+//!     function decrement() public {
+//!         number--;
+//!     }
+//!     function version() public pure returns (uint256) {
+//!         return 2;
+//!     }
+//! }
+//! ```
+//!
+//! The program is ABI-equivalent with Solidity, which means you can call it from both Solidity and Rust.
+//! To do this, run `cargo stylus export-abi`.
+//!
+//! Note: this code is a template-only and has not been audited.
+//!
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+/// Use an efficient WASM allocator.
+#[global_allocator]
+static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
+
+use alloy_sol_types::{Panic, PanicKind, SolError};
+pub use counter::*;
+/// Import items from the SDK. The prelude contains common traits and macros.
+use stylus_sdk::{alloy_primitives::U256, prelude::*};
+
+// Define some persistent storage using the Solidity ABI.
+// `user_main` below is the entrypoint, routing to `CounterV2`.
+sol_storage! {
+    pub struct CounterV2 {
+        #[borrow]
+        Counter counter;
+    }
+}
+
+/// `CounterV2` is the only storage of the program.
+unsafe impl TopLevelStorage for CounterV2 {}
+
+/// Routes calls to `CounterV2`, and through it to `Counter`, with the storage rooted at
+/// [`COUNTER_STORAGE_LOCATION`] as in v1.
+#[entrypoint]
+fn user_main(input: Vec<u8>) -> ArbResult {
+    route_namespaced::<CounterV2>(input)
+}
+
+/// Prints the Solidity ABI, followed by the storage namespace it is rooted at.
+#[cfg(feature = "export-abi")]
+pub fn print_abi(license: &str, pragma: &str) {
+    stylus_sdk::abi::export::print_abi::<CounterV2>(license, pragma);
+    println!();
+    println!("{STORAGE_NATSPEC}");
+}
+
+/// Declare that `CounterV2` is a contract with the following external methods, on top of those
+/// of `Counter`.
+#[external]
+#[inherit(Counter)]
+impl CounterV2 {
+    /// Decrements `number`, reverting like Solidity when it is already zero. It is restricted
+    /// like `increment` once there is an owner.
+    pub fn decrement(&mut self) -> Result<(), Vec<u8>> {
+        let number = self
+            .counter
+            .number()
+            .checked_sub(U256::from(1))
+            .ok_or_else(|| Panic::from(PanicKind::UnderOverflow).abi_encode())?;
+        self.counter.set_number(number)
+    }
+
+    /// The version of this implementation.
+    pub fn version() -> U256 {
+        U256::from(2)
+    }
+}
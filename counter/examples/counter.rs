@@ -1,8 +1,10 @@
-//! Example on how to interact with a deployed `stylus-hello-world` program using defaults.
+//! Example on how to interact with a deployed `counter` program using defaults.
 //! This example uses ethers-rs to instantiate the program using a Solidity ABI.
 //! Then, it attempts to check the current counter value, increment it via a tx,
 //! and check the value again. The deployed program is fully written in Rust and compiled to WASM
 //! but with Stylus, it is accessible just as a normal Solidity smart contract is via an ABI.
+//! A counter behind a proxy with an owner can only be incremented by the owner, so the wallet
+//! must be the owner there.
 
 use ethers::{
     middleware::SignerMiddleware,
@@ -0,0 +1,123 @@
+//! Example on how a deployed `counter` program records who last updated its number and when.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It sets and increments the number, and checks after each transaction that `lastUpdate` and the
+//! `NumberUpdated` event carry the sender and timestamp of the transaction. The block number is
+//! Arbitrum's estimate of the L1 block, so it is only checked not to go backwards. If the counter
+//! has an owner, who alone can then change the number directly, the wallet must be it.
+
+use ethers::{
+    contract::parse_log,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, TransactionReceipt},
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            event NumberUpdated(uint256 number, address indexed updater, uint64 blockNumber, uint64 timestamp)
+            function number() external view returns (uint256)
+            function setNumber(uint256 number) external
+            function increment() external
+            function lastUpdate() external view returns (address, uint64, uint64)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider.clone(),
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    let value = counter.number().call().await? + U256::from(7);
+    let receipt = counter
+        .set_number(value)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let (updater, block_number, timestamp) = counter.last_update().call().await?;
+    let updated: NumberUpdatedFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!(value, updated.number);
+    assert_eq!(
+        (updater, block_number, timestamp),
+        (updated.updater, updated.block_number, updated.timestamp)
+    );
+    assert_eq!(
+        (wallet.address(), timestamp),
+        mined_by(&provider, &receipt).await?
+    );
+    println!("setNumber recorded {updater:?} at block {block_number}, time {timestamp}");
+
+    let receipt = counter
+        .increment()
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let updated: NumberUpdatedFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!(value + U256::one(), updated.number);
+    let (updater, next_block_number, timestamp) = counter.last_update().call().await?;
+    assert_eq!(
+        (updated.updater, updated.block_number, updated.timestamp),
+        (updater, next_block_number, timestamp)
+    );
+    assert_eq!((updater, timestamp), mined_by(&provider, &receipt).await?);
+    assert!(next_block_number >= block_number);
+    println!("increment recorded its own update");
+
+    Ok(())
+}
+
+/// The sender and block timestamp of a mined transaction.
+async fn mined_by(
+    provider: &Provider<Http>,
+    receipt: &TransactionReceipt,
+) -> eyre::Result<(Address, u64)> {
+    let block_number = receipt
+        .block_number
+        .ok_or_else(|| eyre!("Receipt without block number"))?;
+    let block = provider
+        .get_block(block_number)
+        .await?
+        .ok_or_else(|| eyre!("No block {block_number}"))?;
+    Ok((receipt.from, block.timestamp.as_u64()))
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,97 @@
+//! Example on how the storage of a deployed `counter` program is rooted at its ERC-7201 namespace.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It recomputes the `counter.storage` location from the ERC-7201 formula and checks it against
+//! the program's constant and its `storageLocation` view, then sets the number and reads it back
+//! from the namespace slot, with slot 0 left untouched. If the counter has an owner, the wallet
+//! must be it to set the number.
+
+use ethers::{
+    abi::{encode, Token},
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, H256},
+    utils::keccak256,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+use stylus_counter::COUNTER_STORAGE_LOCATION;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let location = erc7201("counter.storage");
+    assert_eq!(location.0, COUNTER_STORAGE_LOCATION.to_be_bytes::<32>());
+    println!("counter.storage is rooted at {location:?}");
+
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function storageLocation() external pure returns (bytes32)
+            function number() external view returns (uint256)
+            function setNumber(uint256 number) external
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider.clone(),
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+    assert_eq!(location.0, counter.storage_location().call().await?);
+
+    let value = counter.number().call().await? + U256::from(1);
+    counter.set_number(value).send().await?.await?;
+    assert_eq!(value, counter.number().call().await?);
+    assert_eq!(
+        H256::from_uint(&value),
+        provider.get_storage_at(address, location, None).await?
+    );
+    assert_eq!(
+        H256::zero(),
+        provider.get_storage_at(address, H256::zero(), None).await?
+    );
+    println!("number is stored at the namespace root, and slot 0 is empty");
+
+    Ok(())
+}
+
+/// `keccak256(abi.encode(uint256(keccak256(namespace)) - 1)) & ~bytes32(uint256(0xff))`
+fn erc7201(namespace: &str) -> H256 {
+    let id = U256::from(keccak256(namespace)) - 1;
+    let mut location = keccak256(encode(&[Token::Uint(id)]));
+    location[31] = 0;
+    H256(location)
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,279 @@
+//! Example on how a deployed `counter` program timelocks large changes to its number.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It expects the counter behind a proxy, such as `counter-proxy`, whose admin appointed the
+//! wallet as the owner. It sets a short minimum delay through the timelock, checks that a large
+//! `setNumber` reverts when sent directly, as do small ones once the direct changes of the window
+//! add up to the limit, then queues it, checks that it can't be executed early and executes it
+//! once ready. A second queued number is cancelled and can't be executed anymore.
+//! The operation ids are recomputed from the `CallScheduled` and `CallSalt` events the way
+//! OpenZeppelin's `TimelockController.hashOperation` does.
+
+use ethers::{
+    abi::{encode, Token},
+    contract::parse_log,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    utils::keccak256,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use stylus_counter::MAX_DIRECT_CHANGE;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// The minimum delay the example sets, in seconds.
+const MIN_DELAY: u64 = 5;
+
+/// `getOperationState` values, as in OpenZeppelin's `TimelockController.OperationState`.
+const UNSET: u8 = 0;
+const WAITING: u8 = 1;
+const READY: u8 = 2;
+const DONE: u8 = 3;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            event CallScheduled(bytes32 indexed id, uint256 indexed index, address target, uint256 value, bytes data, bytes32 predecessor, uint256 delay)
+            event CallExecuted(bytes32 indexed id, uint256 indexed index, address target, uint256 value, bytes data)
+            event CallSalt(bytes32 indexed id, bytes32 salt)
+            event Cancelled(bytes32 indexed id)
+            event MinDelayChange(uint256 oldDuration, uint256 newDuration)
+            error DirectChangeLimitExceeded(uint256 changed, uint256 step, uint256 limit)
+            error TimelockUnexpectedOperationState(bytes32 operationId, bytes32 expectedStates)
+            error TimelockUnexpectedOperationCall(bytes32 operationId, bytes4 selector)
+            function owner() external view returns (address)
+            function number() external view returns (uint256)
+            function setNumber(uint256 number) external
+            function queueSetNumber(uint256 value) external returns (bytes32)
+            function executeSetNumber(bytes32 id) external
+            function cancel(bytes32 id) external
+            function queueUpdateDelay(uint256 newDelay) external returns (bytes32)
+            function executeUpdateDelay(bytes32 id) external
+            function getMinDelay() external view returns (uint256)
+            function getTimestamp(bytes32 id) external view returns (uint256)
+            function getOperationState(bytes32 id) external view returns (uint8)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    assert_eq!(wallet.address(), counter.owner().call().await?);
+
+    // The delay is timelocked by itself, and can't be executed as a `setNumber`.
+    let receipt = counter
+        .queue_update_delay(U256::from(MIN_DELAY))
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let scheduled: CallScheduledFilter = parse_log(receipt.logs[0].clone())?;
+    while counter.get_operation_state(scheduled.id).call().await? != READY {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    let err = counter
+        .execute_set_number(scheduled.id)
+        .call()
+        .await
+        .expect_err("executeSetNumber should revert");
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::TimelockUnexpectedOperationCall(err)) => {
+            assert_eq!(scheduled.id, err.operation_id);
+            assert_eq!(&scheduled.data[..4], &err.selector[..]);
+        }
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    }
+    let receipt = counter
+        .execute_update_delay(scheduled.id)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let changed: MinDelayChangeFilter = receipt
+        .logs
+        .into_iter()
+        .find_map(|log| parse_log(log).ok())
+        .ok_or_else(|| eyre!("No MinDelayChange event"))?;
+    assert_eq!(U256::from(MIN_DELAY), changed.new_duration);
+    assert_eq!(U256::from(MIN_DELAY), counter.get_min_delay().call().await?);
+    println!("Updated the minimum delay to {MIN_DELAY}s through the timelock");
+
+    // A large jump can't be made directly, and neither can small steps adding up to one.
+    let limit = U256::from(MAX_DIRECT_CHANGE.to::<u64>());
+    counter.increment().send().await?.await?;
+    let number = counter.number().call().await?;
+    let value = number + U256::from(1_000_000);
+    let err = counter
+        .set_number(value)
+        .call()
+        .await
+        .expect_err("setNumber should revert");
+    let changed = match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::DirectChangeLimitExceeded(err)) => {
+            assert_eq!(U256::from(1_000_000), err.step);
+            assert_eq!(limit, err.limit);
+            assert!(!err.changed.is_zero(), "the increment wasn't counted");
+            err.changed
+        }
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    };
+    let left = limit - changed;
+    counter.set_number(number + left).call().await?;
+    let err = counter
+        .set_number(number + left + U256::one())
+        .call()
+        .await
+        .expect_err("setNumber should revert past the limit");
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::DirectChangeLimitExceeded(err)) => assert_eq!(changed, err.changed),
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    }
+    println!("setNumber({value}) reverts when sent directly, as do steps past {left} more today");
+
+    // Queue it instead and check the announcement.
+    let receipt = counter
+        .queue_set_number(value)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let scheduled: CallScheduledFilter = parse_log(receipt.logs[0].clone())?;
+    // Without a `CallSalt` event the salt is zero, as with OpenZeppelin's timelock.
+    let salt = receipt
+        .logs
+        .iter()
+        .find_map(|log| parse_log::<CallSaltFilter>(log.clone()).ok())
+        .map_or([0; 32], |event| event.salt);
+    let id = scheduled.id;
+    assert_eq!(address, scheduled.target);
+    assert_eq!(U256::from(MIN_DELAY), scheduled.delay);
+    let data = counter
+        .set_number(value)
+        .calldata()
+        .ok_or_else(|| eyre!("No calldata"))?;
+    assert_eq!(data, scheduled.data);
+    assert_eq!([0; 32], scheduled.predecessor);
+    assert_eq!(
+        id,
+        hash_operation(address, &data, scheduled.predecessor, salt)
+    );
+    assert_eq!(WAITING, counter.get_operation_state(id).call().await?);
+    println!("Queued setNumber({value}) as {}", hex::encode(id));
+
+    // Too early.
+    let err = counter
+        .execute_set_number(id)
+        .call()
+        .await
+        .expect_err("executeSetNumber should revert");
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::TimelockUnexpectedOperationState(err)) => {
+            assert_eq!(id, err.operation_id);
+            assert_eq!(U256::one() << READY, U256::from(err.expected_states));
+        }
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    }
+    assert_ne!(value, counter.number().call().await?);
+
+    let ready_at = counter.get_timestamp(id).call().await?;
+    while counter.get_operation_state(id).call().await? != READY {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    let receipt = counter
+        .execute_set_number(id)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let executed: CallExecutedFilter = receipt
+        .logs
+        .into_iter()
+        .find_map(|log| parse_log(log).ok())
+        .ok_or_else(|| eyre!("No CallExecuted event"))?;
+    assert_eq!(id, executed.id);
+    assert_eq!(data, executed.data);
+    assert_eq!(value, counter.number().call().await?);
+    assert_eq!(DONE, counter.get_operation_state(id).call().await?);
+    assert!(counter.execute_set_number(id).call().await.is_err());
+    println!("Executed setNumber({value}) once ready at {ready_at}");
+
+    // Queue another number and cancel it.
+    let receipt = counter
+        .queue_set_number(U256::zero())
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let scheduled: CallScheduledFilter = parse_log(receipt.logs[0].clone())?;
+    let receipt = counter
+        .cancel(scheduled.id)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let cancelled: CancelledFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!(scheduled.id, cancelled.id);
+    assert_eq!(
+        UNSET,
+        counter.get_operation_state(scheduled.id).call().await?
+    );
+    assert!(counter
+        .execute_set_number(scheduled.id)
+        .call()
+        .await
+        .is_err());
+    assert_eq!(value, counter.number().call().await?);
+    println!("Cancelled setNumber(0)");
+
+    Ok(())
+}
+
+/// `hashOperation(target, 0, data, predecessor, salt)` of OpenZeppelin's `TimelockController`.
+fn hash_operation(target: Address, data: &[u8], predecessor: [u8; 32], salt: [u8; 32]) -> [u8; 32] {
+    keccak256(encode(&[
+        Token::Address(target),
+        Token::Uint(U256::zero()),
+        Token::Bytes(data.to_vec()),
+        Token::FixedBytes(predecessor.to_vec()),
+        Token::FixedBytes(salt.to_vec()),
+    ]))
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
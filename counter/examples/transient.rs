@@ -0,0 +1,104 @@
+//! Example on how the transient number of a deployed `counter` program resets between transactions.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It sends `transientIncrement` twice in one transaction through `multicall` and checks the
+//! second sees the first, then sends it in several transactions and checks that each one starts
+//! again from zero, and that nothing is left in persistent storage under the transient key.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, H256},
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+use stylus_counter::COUNTER_TRANSIENT_LOCATION;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function transientIncrement() external returns (uint256)
+            function transientNumber() external view returns (uint256)
+            function multicall(bytes[] data) external returns (bytes[])
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let key = H256(COUNTER_TRANSIENT_LOCATION.to_be_bytes::<32>());
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider.clone(),
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // Within a transaction, the transient number carries over from one call to the next.
+    let increment = counter
+        .transient_increment()
+        .calldata()
+        .ok_or_else(|| eyre!("No calldata"))?;
+    let outputs = counter
+        .multicall(vec![increment.clone(), increment.clone()])
+        .call()
+        .await?;
+    let numbers: Vec<U256> = outputs
+        .iter()
+        .map(|output| U256::from_big_endian(output))
+        .collect();
+    assert_eq!(vec![U256::one(), U256::from(2)], numbers);
+    counter
+        .multicall(vec![increment.clone(), increment])
+        .send()
+        .await?
+        .await?;
+    println!("Two transientIncrements in one transaction count up to 2");
+
+    for _ in 0..3 {
+        assert_eq!(U256::zero(), counter.transient_number().call().await?);
+        assert_eq!(U256::one(), counter.transient_increment().call().await?);
+        counter.transient_increment().send().await?.await?;
+    }
+    assert_eq!(U256::zero(), counter.transient_number().call().await?);
+    println!("transientIncrement starts from zero in every transaction");
+
+    assert_eq!(
+        H256::zero(),
+        provider.get_storage_at(address, key, None).await?
+    );
+    println!("Nothing is persisted under the transient key");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
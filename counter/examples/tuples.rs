@@ -0,0 +1,82 @@
+//! Example on how multiple return values of a deployed `counter` program are decoded.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It reads the counter and the caller with a single call to `numberAndSender`, which returns
+//! both. If the counter has an owner, setting the number first needs the wallet to be it.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function number() external view returns (uint256)
+            function setNumber(uint256 number) external
+            function numberAndSender() external view returns (uint256, address)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    let value = counter.number().call().await? + U256::from(7);
+    let pending = counter.set_number(value);
+    if let Some(receipt) = pending.send().await?.await? {
+        println!("Receipt = {:?}", receipt);
+    }
+    println!("Successfully set counter via a tx");
+
+    let (number, sender) = counter
+        .number_and_sender()
+        .from(wallet.address())
+        .call()
+        .await?;
+    println!("Counter number = {:?}, sender = {:?}", number, sender);
+    assert_eq!(value, number);
+    assert_eq!(wallet.address(), sender);
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
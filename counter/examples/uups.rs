@@ -0,0 +1,186 @@
+//! Example on how a `counter` program behind an ERC-1967 proxy upgrades the proxy itself (UUPS).
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It expects a deployed proxy, such as `counter-proxy`, delegating to a deployed `counter`
+//! program, whose admin appointed the wallet as the owner, as `counter-proxy`'s `upgrade`
+//! example does. It checks that strangers can't appoint themselves, that `proxiableUUID` and
+//! `upgradeToAndCall` only answer on the bare implementation and through the proxy respectively,
+//! and that strangers and implementations without the right `proxiableUUID` are rejected. It then
+//! upgrades to a deployed `counter-v2` program with `setNumber` as the initializer and checks the
+//! implementation slot, the `Upgraded` event and the number.
+
+use ethers::{
+    contract::parse_log,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes, H256},
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+use stylus_counter::IMPLEMENTATION_SLOT;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Deployed `counter-v2` program address.
+const V2_PROGRAM_ADDRESS: &str = "V2_ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    let v2_address = std::env::var(V2_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", V2_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            event Upgraded(address indexed implementation)
+            error Unauthorized(address caller)
+            error ERC1967InvalidImplementation(address implementation)
+            error UUPSUnsupportedProxiableUUID(bytes32 slot)
+            error UUPSUnauthorizedCallContext()
+            function initializeOwner(address owner, uint256 minDelay) external
+            function owner() external view returns (address)
+            function proxiableUUID() external view returns (bytes32)
+            function upgradeToAndCall(address newImplementation, bytes data) external payable
+            function number() external view returns (uint256)
+            function setNumber(uint256 number) external
+            function version() external view returns (uint256)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let v2: Address = v2_address.parse()?;
+    let slot = H256(IMPLEMENTATION_SLOT.to_be_bytes::<32>());
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider.clone(),
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    // `counter-v2` inherits every method of v1, so its ABI serves for both versions behind the
+    // proxy.
+    let counter = Counter::new(address, client.clone());
+    let stranger = Address::repeat_byte(0x11);
+
+    // Only the proxy's admin may appoint the owner, and it already has.
+    assert_eq!(wallet.address(), counter.owner().call().await?);
+    let err = counter
+        .initialize_owner(stranger, U256::one())
+        .from(stranger)
+        .call()
+        .await
+        .expect_err("initializeOwner should revert");
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::Unauthorized(Unauthorized { caller })) => assert_eq!(stranger, caller),
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    }
+
+    // `proxiableUUID` only answers on the bare implementation, and `upgradeToAndCall` and
+    // `initializeOwner` only through the proxy.
+    let err = counter
+        .proxiable_uuid()
+        .call()
+        .await
+        .expect_err("proxiableUUID should revert through the proxy");
+    assert!(matches!(
+        err.decode_contract_revert::<CounterErrors>(),
+        Some(CounterErrors::UUPSUnauthorizedCallContext(_))
+    ));
+    let v1 = Address::from(provider.get_storage_at(address, slot, None).await?);
+    let implementation = Counter::new(v1, client);
+    assert_eq!(slot.0, implementation.proxiable_uuid().call().await?);
+    for err in [
+        implementation
+            .upgrade_to_and_call(v2, Bytes::new())
+            .call()
+            .await
+            .expect_err("upgradeToAndCall should revert on the implementation"),
+        implementation
+            .initialize_owner(wallet.address(), U256::one())
+            .call()
+            .await
+            .expect_err("initializeOwner should revert on the implementation"),
+    ] {
+        assert!(matches!(
+            err.decode_contract_revert::<CounterErrors>(),
+            Some(CounterErrors::UUPSUnauthorizedCallContext(_))
+        ));
+    }
+    assert!(implementation.owner().call().await?.is_zero());
+    println!("The implementation can't be upgraded or owned, nor the proxy mistaken for one");
+
+    // Only the owner may upgrade.
+    let err = counter
+        .upgrade_to_and_call(v2, Bytes::new())
+        .from(stranger)
+        .call()
+        .await
+        .expect_err("upgradeToAndCall should revert");
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::Unauthorized(Unauthorized { caller })) => assert_eq!(stranger, caller),
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    }
+
+    // An address without `proxiableUUID` can't become the implementation.
+    let err = counter
+        .upgrade_to_and_call(stranger, Bytes::new())
+        .call()
+        .await
+        .expect_err("upgradeToAndCall should revert");
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::ERC1967InvalidImplementation(err)) => {
+            assert_eq!(stranger, err.implementation)
+        }
+        other => return Err(eyre!("unexpected revert: {other:?}")),
+    }
+    println!("upgradeToAndCall rejects strangers and non-UUPS implementations");
+
+    let value = counter.number().call().await? + U256::from(1);
+    let init = counter
+        .set_number(value)
+        .calldata()
+        .ok_or_else(|| eyre!("No calldata"))?;
+    let receipt = counter
+        .upgrade_to_and_call(v2, init)
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let upgraded: UpgradedFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!(v2, upgraded.implementation);
+    assert_eq!(
+        H256::from(v2),
+        provider.get_storage_at(address, slot, None).await?
+    );
+    assert_eq!(value, counter.number().call().await?);
+    assert_eq!(U256::from(2), counter.version().call().await?);
+    println!("Upgraded the proxy to {v2:?} and initialized the number");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -15,6 +15,25 @@
 //! }
 //! ```
 //!
+//! Unlike Foundry's, the storage of this `Counter` starts at the ERC-7201 namespace
+//! `counter.storage` rather than at slot 0, so it can't collide with a proxy or a mixin module.
+//! Behind an ERC-1967 proxy it is also a UUPS implementation: its owner upgrades the proxy
+//! through `upgradeToAndCall` on the counter itself. A second, transient number lives in EIP-1153
+//! transient storage and is back to zero in every transaction.
+//! Deployed on its own, the counter has no owner and anyone can change the number, as with
+//! Foundry's. Once an owner is appointed, only the owner can change it directly, and by at most
+//! `MAX_DIRECT_CHANGE` in total per `DIRECT_CHANGE_WINDOW`. Larger jumps have to be announced ahead of time: `queueSetNumber` schedules a `setNumber` that
+//! `executeSetNumber` only performs after a minimum delay, in the style of OpenZeppelin's
+//! `TimelockController`. The delay is set when the owner is appointed and is never zero. It only
+//! changes the same way, through `queueUpdateDelay` and `executeUpdateDelay`.
+//!
+//! Two addresses can upgrade. The proxy's admin, such as `counter-proxy`'s, can always repoint
+//! the proxy from outside the counter, and is the only one who can appoint the owner, once, with
+//! `initializeOwner`. `counter-proxy` does so in the transaction that initializes it, by passing
+//! that call as its initializer data. The owner then upgrades with `upgradeToAndCall`, and alone
+//! queues and cancels timelocked operations. A counter deployed on its own has no admin, so it
+//! never gets an owner and stays open to everyone.
+//!
 //! The program is ABI-equivalent with Solidity, which means you can call it from both Solidity and Rust.
 //! To do this, run `cargo stylus export-abi`.
 //!
@@ -26,18 +45,389 @@
 extern crate alloc;
 
 /// Use an efficient WASM allocator.
+#[cfg(not(feature = "library"))]
 #[global_allocator]
 static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
 
+pub mod transient;
+
+use alloy_sol_types::{sol, SolCall, SolError, SolValue};
 /// Import items from the SDK. The prelude contains common traits and macros.
-use stylus_sdk::{alloy_primitives::U256, prelude::*};
+use stylus_sdk::{
+    abi::{Bytes, Router},
+    alloy_primitives::{self, uint, Address, B256, U256, U64},
+    block,
+    call::{delegate_call, Call},
+    contract, crypto, evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageCache},
+};
+
+/// The ERC-7201 location of the `counter.storage` namespace,
+/// `keccak256(abi.encode(uint256(keccak256("counter.storage")) - 1)) & ~bytes32(uint256(0xff))`.
+pub const COUNTER_STORAGE_LOCATION: U256 =
+    uint!(0x3a8940d2c88113c2296117248b8b2aedcf41634993b4c0b4ea1a36805e66c300_U256);
+
+/// The ERC-7201 location of the `counter.transient` namespace, which keys the transient number.
+pub const COUNTER_TRANSIENT_LOCATION: U256 =
+    uint!(0x619f93b7d380de72c462ac2ff949593833a94d3a08614a5ce803f1d4e692a400_U256);
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`, which is also the
+/// ERC-1822 `proxiableUUID` of a UUPS implementation.
+pub const IMPLEMENTATION_SLOT: U256 =
+    uint!(0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc_U256);
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)`, where the proxy keeps its admin.
+pub const ADMIN_SLOT: U256 =
+    uint!(0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103_U256);
+
+/// How far the owner can move the number directly, in total, within one `DIRECT_CHANGE_WINDOW`.
+/// Larger changes go through the timelock.
+pub const MAX_DIRECT_CHANGE: U256 = uint!(1000_U256);
+
+/// The length of the windows `MAX_DIRECT_CHANGE` applies to, in seconds. The windows are fixed
+/// rather than sliding, so up to twice the limit can be moved around the end of one.
+pub const DIRECT_CHANGE_WINDOW: u64 = 86_400;
+
+/// The namespaced storage as Solidity declares it, which `export-abi` can't annotate itself.
+#[cfg(feature = "export-abi")]
+pub const STORAGE_NATSPEC: &str = "\
+/// @custom:storage-location erc7201:counter.storage
+struct CounterStorage {
+    uint256 number;
+    address owner;
+    address lastUpdater;
+    uint64 lastUpdatedBlock;
+    uint64 lastUpdatedAt;
+    uint256 minDelay;
+    uint256 operationCount;
+    mapping(bytes32 => uint256) timestamps;
+    mapping(bytes32 => uint256) queuedNumbers;
+    mapping(bytes32 => bytes4) queuedSelectors;
+    uint64 directChangeWindow;
+    uint256 directChanged;
+}";
 
 // Define some persistent storage using the Solidity ABI.
-// `Counter` will be the entrypoint.
+// `user_main` below is the entrypoint, routing to `Counter`.
 sol_storage! {
-    #[entrypoint]
     pub struct Counter {
         uint256 number;
+        address owner;
+        address last_updater;
+        uint64 last_updated_block;
+        uint64 last_updated_at;
+        uint256 min_delay;
+        uint256 operation_count;
+        mapping(bytes32 => uint256) timestamps;
+        mapping(bytes32 => uint256) queued_numbers;
+        mapping(bytes32 => bytes4) queued_selectors;
+        uint64 direct_change_window;
+        uint256 direct_changed;
+    }
+}
+
+/// The timestamp a timelocked operation is marked with once executed, as in OpenZeppelin's
+/// `TimelockController`.
+const DONE_TIMESTAMP: U256 = uint!(1_U256);
+
+/// The state of a timelocked operation, as in OpenZeppelin's `TimelockController`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OperationState {
+    Unset,
+    Waiting,
+    Ready,
+    Done,
+}
+
+sol! {
+    event Upgraded(address indexed implementation);
+    event NumberUpdated(
+        uint256 number,
+        address indexed updater,
+        uint64 blockNumber,
+        uint64 timestamp
+    );
+
+    error AlreadyInitialized();
+    error Unauthorized(address caller);
+    error ERC1967InvalidImplementation(address implementation);
+    error UUPSUnsupportedProxiableUUID(bytes32 slot);
+    error UUPSUnauthorizedCallContext();
+    error DirectChangeLimitExceeded(uint256 changed, uint256 step, uint256 limit);
+
+    event CallScheduled(
+        bytes32 indexed id,
+        uint256 indexed index,
+        address target,
+        uint256 value,
+        bytes data,
+        bytes32 predecessor,
+        uint256 delay
+    );
+    event CallExecuted(
+        bytes32 indexed id,
+        uint256 indexed index,
+        address target,
+        uint256 value,
+        bytes data
+    );
+    event CallSalt(bytes32 indexed id, bytes32 salt);
+    event Cancelled(bytes32 indexed id);
+    event MinDelayChange(uint256 oldDuration, uint256 newDuration);
+
+    error TimelockUnexpectedOperationState(bytes32 operationId, bytes32 expectedStates);
+    error TimelockUnexpectedOperationCall(bytes32 operationId, bytes4 selector);
+    error TimelockZeroDelay();
+
+    /// The call a queued `setNumber` stands for.
+    function setNumber(uint256 newNumber);
+    /// The call a queued `updateDelay` stands for.
+    function updateDelay(uint256 newDelay);
+}
+
+sol_interface! {
+    interface IERC1822Proxiable {
+        function proxiableUUID() view returns (bytes32);
+    }
+}
+
+/// `Counter` is the only storage of the program, as `#[entrypoint]` on the struct would declare,
+/// or the start of the storage of a program inheriting it.
+unsafe impl TopLevelStorage for Counter {}
+
+/// Routes calls to `Counter` with its storage rooted at [`COUNTER_STORAGE_LOCATION`], where
+/// `#[entrypoint]` on the struct would root it at slot 0. Left out with the `library` feature,
+/// so another program can route to a type inheriting `Counter` instead.
+#[cfg(not(feature = "library"))]
+#[entrypoint]
+fn user_main(input: Vec<u8>) -> ArbResult {
+    route_namespaced::<Counter>(input)
+}
+
+/// Routes calls to `S`, whose storage starts with `Counter`'s, rooted at
+/// [`COUNTER_STORAGE_LOCATION`].
+pub fn route_namespaced<S>(input: Vec<u8>) -> ArbResult
+where
+    S: StorageType + TopLevelStorage + Router<S>,
+{
+    // Safety: nothing else in the program uses the namespace.
+    let mut storage = unsafe { S::new(COUNTER_STORAGE_LOCATION, 0) };
+    route_call(&mut storage, &input)
+}
+
+/// Routes the call `input` to the method of `S` its selector names.
+fn route_call<S>(storage: &mut S, input: &[u8]) -> ArbResult
+where
+    S: TopLevelStorage + Router<S>,
+{
+    if input.len() < 4 {
+        return Err("calldata too short".into());
+    }
+    let selector = u32::from_be_bytes(input[..4].try_into().unwrap());
+    match S::route(storage, selector, &input[4..]) {
+        Some(result) => result,
+        None => Err(format!("unknown method selector: {selector:08x}").into()),
+    }
+}
+
+/// Prints the Solidity ABI, followed by the storage namespace it is rooted at.
+#[cfg(feature = "export-abi")]
+pub fn print_abi(license: &str, pragma: &str) {
+    stylus_sdk::abi::export::print_abi::<Counter>(license, pragma);
+    println!();
+    println!("{STORAGE_NATSPEC}");
+}
+
+/// The ERC-1967 implementation slot of the storage this runs in: the proxy's behind a proxy, and
+/// empty on the bare implementation.
+fn implementation_slot() -> StorageAddress {
+    // Safety: the slot only ever holds the implementation address, as the proxy reads it.
+    unsafe { StorageAddress::new(IMPLEMENTATION_SLOT, 0) }
+}
+
+/// The admin of the ERC-1967 proxy this runs behind, or zero on the bare implementation.
+fn proxy_admin() -> Address {
+    // Safety: the slot only ever holds the admin address, as the proxy writes it.
+    unsafe { StorageAddress::new(ADMIN_SLOT, 0) }.get()
+}
+
+impl Counter {
+    fn only_owner(&self) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.owner.get() {
+            return Err(Unauthorized { caller }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Fails with `UUPSUnauthorizedCallContext` unless this runs by delegatecall from an
+    /// ERC-1967 proxy, whose implementation slot holds a UUPS implementation. Stylus has no
+    /// immutables to compare the slot with this code's own address, as OpenZeppelin's `__self`
+    /// does, so the implementation is asked for its `proxiableUUID` instead, which only a bare
+    /// implementation answers.
+    fn only_proxy(&mut self) -> Result<(), Vec<u8>> {
+        let implementation = implementation_slot().get();
+        if implementation.is_zero() || implementation == contract::address() {
+            return Err(UUPSUnauthorizedCallContext {}.abi_encode());
+        }
+        match IERC1822Proxiable::new(implementation).proxiable_uuid(Call::new_in(self)) {
+            Ok(slot) if slot == B256::from(IMPLEMENTATION_SLOT) => Ok(()),
+            _ => Err(UUPSUnauthorizedCallContext {}.abi_encode()),
+        }
+    }
+
+    /// Fails with `UUPSUnauthorizedCallContext` when this runs behind a proxy, as the bare
+    /// implementation's own implementation slot is never set.
+    fn not_delegated(&self) -> Result<(), Vec<u8>> {
+        if !implementation_slot().get().is_zero() {
+            return Err(UUPSUnauthorizedCallContext {}.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// The state of the operation `id`, with a queued one ready once its timestamp is reached.
+    fn operation_state(&self, id: B256) -> OperationState {
+        let timestamp = self.timestamps.get(id);
+        if timestamp.is_zero() {
+            OperationState::Unset
+        } else if timestamp == DONE_TIMESTAMP {
+            OperationState::Done
+        } else if timestamp > U256::from(block::timestamp()) {
+            OperationState::Waiting
+        } else {
+            OperationState::Ready
+        }
+    }
+
+    /// Fails with `TimelockUnexpectedOperationState` unless the operation `id` is in one of
+    /// `expected`.
+    fn expect_state(&self, id: B256, expected: &[OperationState]) -> Result<(), Vec<u8>> {
+        if expected.contains(&self.operation_state(id)) {
+            return Ok(());
+        }
+        let bitmap = expected.iter().fold(U256::ZERO, |bitmap, &state| {
+            bitmap | (U256::from(1) << state as usize)
+        });
+        Err(TimelockUnexpectedOperationState {
+            operationId: id,
+            expectedStates: bitmap.into(),
+        }
+        .abi_encode())
+    }
+
+    /// Sets the number, recording who updated it and when. Both direct changes and executed
+    /// operations go through it.
+    fn write_number(&mut self, new_number: U256) {
+        let updater = msg::sender();
+        let block_number = block::number();
+        let timestamp = block::timestamp();
+        self.number.set(new_number);
+        self.last_updater.set(updater);
+        self.last_updated_block.set(U64::from(block_number));
+        self.last_updated_at.set(U64::from(timestamp));
+        evm::log(NumberUpdated {
+            number: new_number,
+            updater,
+            blockNumber: block_number,
+            timestamp,
+        });
+    }
+
+    /// Queues the call `data` to this contract, whose only argument is `value`, to be executed
+    /// once the minimum delay has passed. `selector` is kept so it can only be executed as that
+    /// call. Only the owner can queue. As in OpenZeppelin's `TimelockController`, a nonzero salt
+    /// is logged with `CallSalt`, so the id can be recomputed from the logs.
+    fn schedule(
+        &mut self,
+        selector: [u8; 4],
+        data: alloy_primitives::Bytes,
+        value: U256,
+    ) -> Result<B256, Vec<u8>> {
+        self.only_owner()?;
+        let index = self.operation_count.get();
+        self.operation_count.set(index + U256::from(1));
+        let salt = B256::from(index);
+        let id = Self::operation_id(&data, salt);
+        let delay = self.min_delay.get();
+        let ready_at = U256::from(block::timestamp()).saturating_add(delay);
+        self.timestamps.setter(id).set(ready_at);
+        self.queued_numbers.setter(id).set(value);
+        self.queued_selectors.setter(id).set(selector.into());
+        evm::log(CallScheduled {
+            id,
+            index: U256::ZERO,
+            target: contract::address(),
+            value: U256::ZERO,
+            data,
+            predecessor: B256::ZERO,
+            delay,
+        });
+        if !salt.is_zero() {
+            evm::log(CallSalt { id, salt });
+        }
+        Ok(id)
+    }
+
+    /// Marks the ready operation `id` done and returns its argument. Fails with
+    /// `TimelockUnexpectedOperationCall` unless it was queued for `selector`.
+    fn execute(&mut self, id: B256, selector: [u8; 4]) -> Result<U256, Vec<u8>> {
+        self.expect_state(id, &[OperationState::Ready])?;
+        let queued = self.queued_selectors.get(id);
+        if queued != selector {
+            return Err(TimelockUnexpectedOperationCall {
+                operationId: id,
+                selector: queued,
+            }
+            .abi_encode());
+        }
+        self.timestamps.setter(id).set(DONE_TIMESTAMP);
+        Ok(self.queued_numbers.get(id))
+    }
+
+    /// Logs the execution of the operation `id`, which called this contract with `data`.
+    fn executed(id: B256, data: alloy_primitives::Bytes) {
+        evm::log(CallExecuted {
+            id,
+            index: U256::ZERO,
+            target: contract::address(),
+            value: U256::ZERO,
+            data,
+        });
+    }
+
+    /// The call data a `setNumber` operation stands for.
+    fn set_number_data(new_number: U256) -> alloy_primitives::Bytes {
+        setNumberCall {
+            newNumber: new_number,
+        }
+        .abi_encode()
+        .into()
+    }
+
+    /// The call data an `updateDelay` operation stands for.
+    fn update_delay_data(new_delay: U256) -> alloy_primitives::Bytes {
+        updateDelayCall {
+            newDelay: new_delay,
+        }
+        .abi_encode()
+        .into()
+    }
+
+    /// The id of an operation calling this contract with `data`, as OpenZeppelin's
+    /// `hashOperation` computes it for no value and no predecessor.
+    fn operation_id(data: &alloy_primitives::Bytes, salt: B256) -> B256 {
+        crypto::keccak(
+            (
+                contract::address(),
+                U256::ZERO,
+                data.clone(),
+                B256::ZERO,
+                salt,
+            )
+                .abi_encode_params(),
+        )
     }
 }
 
@@ -49,24 +439,244 @@ impl Counter {
         self.number.get()
     }
 
-    /// Sets a number in storage to a user-specified value.
-    pub fn set_number(&mut self, new_number: U256) {
-        self.number.set(new_number);
+    /// Gets the ERC-7201 location the storage is rooted at.
+    pub fn storage_location() -> B256 {
+        COUNTER_STORAGE_LOCATION.into()
+    }
+
+    /// Makes `owner` the owner, who alone can upgrade through `upgrade_to_and_call` and queue
+    /// timelocked operations, which wait at least `min_delay`. Only the admin of the proxy this
+    /// runs behind can, and only once. The delay can't be zero, or queued operations could be
+    /// executed in the same block.
+    pub fn initialize_owner(&mut self, owner: Address, min_delay: U256) -> Result<(), Vec<u8>> {
+        self.only_proxy()?;
+        let caller = msg::sender();
+        if caller != proxy_admin() {
+            return Err(Unauthorized { caller }.abi_encode());
+        }
+        if !self.owner.get().is_zero() {
+            return Err(AlreadyInitialized {}.abi_encode());
+        }
+        if min_delay.is_zero() {
+            return Err(TimelockZeroDelay {}.abi_encode());
+        }
+        self.owner.set(owner);
+        evm::log(MinDelayChange {
+            oldDuration: self.min_delay.get(),
+            newDuration: min_delay,
+        });
+        self.min_delay.set(min_delay);
+        Ok(())
+    }
+
+    /// Gets the address allowed to upgrade.
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// The ERC-1822 UUID of this implementation: the ERC-1967 slot `upgrade_to_and_call` writes.
+    /// Reverts behind a proxy, so a proxy can't be mistaken for an implementation and upgraded
+    /// to, which would make it delegate to itself.
+    #[selector(name = "proxiableUUID")]
+    pub fn proxiable_uuid(&self) -> Result<B256, Vec<u8>> {
+        self.not_delegated()?;
+        Ok(IMPLEMENTATION_SLOT.into())
+    }
+
+    /// Points the ERC-1967 proxy this runs behind at `new_implementation`, then delegatecalls
+    /// `data` to it as an initializer unless empty. Only the owner can upgrade, only through the
+    /// proxy, and only to an implementation whose `proxiableUUID` is the implementation slot, so
+    /// the proxy can't be left behind code that can't upgrade it again.
+    #[payable]
+    pub fn upgrade_to_and_call(
+        &mut self,
+        new_implementation: Address,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        self.only_proxy()?;
+        self.only_owner()?;
+        let slot = IERC1822Proxiable::new(new_implementation)
+            .proxiable_uuid(Call::new_in(self))
+            .map_err(|_| {
+                ERC1967InvalidImplementation {
+                    implementation: new_implementation,
+                }
+                .abi_encode()
+            })?;
+        if slot != B256::from(IMPLEMENTATION_SLOT) {
+            return Err(UUPSUnsupportedProxiableUUID { slot }.abi_encode());
+        }
+        implementation_slot().set(new_implementation);
+        evm::log(Upgraded {
+            implementation: new_implementation,
+        });
+        if !data.is_empty() {
+            // The new implementation's `only_proxy` reads the slot just written, so it has to
+            // reach the state before the initializer runs, and cached values it may change are
+            // dropped.
+            StorageCache::clear();
+            // Safety: the new implementation is trusted with the storage, as the upgrade implies.
+            unsafe { delegate_call(Call::new_in(self), new_implementation, &data) }?;
+        }
+        Ok(())
+    }
+
+    /// Gets the number from storage together with the caller, as multiple return values.
+    pub fn number_and_sender(&self) -> (U256, Address) {
+        (self.number.get(), msg::sender())
+    }
+
+    /// Sets a number in storage to a user-specified value. Once there is an owner, only the owner
+    /// can, and only while the changes made this way add up to at most `MAX_DIRECT_CHANGE` in the
+    /// current `DIRECT_CHANGE_WINDOW`; larger changes have to be queued. Every other direct
+    /// mutation goes through it.
+    pub fn set_number(&mut self, new_number: U256) -> Result<(), Vec<u8>> {
+        if self.owner.get().is_zero() {
+            self.write_number(new_number);
+            return Ok(());
+        }
+        self.only_owner()?;
+        let number = self.number.get();
+        let step = if new_number > number {
+            new_number - number
+        } else {
+            number - new_number
+        };
+        let window = U64::from(block::timestamp() / DIRECT_CHANGE_WINDOW);
+        let changed = if self.direct_change_window.get() == window {
+            self.direct_changed.get()
+        } else {
+            U256::ZERO
+        };
+        let total = changed.saturating_add(step);
+        if total > MAX_DIRECT_CHANGE {
+            return Err(DirectChangeLimitExceeded {
+                changed,
+                step,
+                limit: MAX_DIRECT_CHANGE,
+            }
+            .abi_encode());
+        }
+        self.direct_change_window.set(window);
+        self.direct_changed.set(total);
+        self.write_number(new_number);
+        Ok(())
     }
 
     /// Sets a number in storage to a user-specified value.
-    pub fn mul_number(&mut self, new_number: U256) {
-        self.number.set(new_number * self.number.get());
+    pub fn mul_number(&mut self, new_number: U256) -> Result<(), Vec<u8>> {
+        self.set_number(new_number * self.number.get())
     }
 
     /// Sets a number in storage to a user-specified value.
-    pub fn sub_number(&mut self, new_number: U256) {
-        self.number.set(new_number + self.number.get());
+    pub fn sub_number(&mut self, new_number: U256) -> Result<(), Vec<u8>> {
+        self.set_number(new_number + self.number.get())
+    }
+
+    /// Queues setting the number to `value`, which can be executed once the minimum delay has
+    /// passed. Only the owner can queue. Each operation is salted with its index, so queueing the
+    /// same value twice yields two operations.
+    pub fn queue_set_number(&mut self, value: U256) -> Result<B256, Vec<u8>> {
+        self.schedule(setNumberCall::SELECTOR, Self::set_number_data(value), value)
+    }
+
+    /// Sets the number as queued by the ready operation `id`, however far from the current one.
+    /// Anyone can execute.
+    pub fn execute_set_number(&mut self, id: B256) -> Result<(), Vec<u8>> {
+        let value = self.execute(id, setNumberCall::SELECTOR)?;
+        self.write_number(value);
+        Self::executed(id, Self::set_number_data(value));
+        Ok(())
+    }
+
+    /// Queues setting the delay between queueing an operation and it becoming ready to
+    /// `new_delay`. It is timelocked by the current delay, so it can't be dropped to skip it, and
+    /// can't be zero. Only the owner can queue.
+    pub fn queue_update_delay(&mut self, new_delay: U256) -> Result<B256, Vec<u8>> {
+        if new_delay.is_zero() {
+            return Err(TimelockZeroDelay {}.abi_encode());
+        }
+        self.schedule(
+            updateDelayCall::SELECTOR,
+            Self::update_delay_data(new_delay),
+            new_delay,
+        )
+    }
+
+    /// Sets the delay as queued by the ready operation `id`. Operations already queued keep their
+    /// timestamp. Anyone can execute.
+    pub fn execute_update_delay(&mut self, id: B256) -> Result<(), Vec<u8>> {
+        let new_delay = self.execute(id, updateDelayCall::SELECTOR)?;
+        evm::log(MinDelayChange {
+            oldDuration: self.min_delay.get(),
+            newDuration: new_delay,
+        });
+        self.min_delay.set(new_delay);
+        Self::executed(id, Self::update_delay_data(new_delay));
+        Ok(())
+    }
+
+    /// Cancels the operation `id` while it is waiting or ready. Only the owner can cancel.
+    pub fn cancel(&mut self, id: B256) -> Result<(), Vec<u8>> {
+        self.only_owner()?;
+        self.expect_state(id, &[OperationState::Waiting, OperationState::Ready])?;
+        self.timestamps.delete(id);
+        self.queued_numbers.delete(id);
+        self.queued_selectors.delete(id);
+        evm::log(Cancelled { id });
+        Ok(())
+    }
+
+    /// Gets the delay between queueing an operation and it becoming ready.
+    pub fn get_min_delay(&self) -> U256 {
+        self.min_delay.get()
+    }
+
+    /// Gets the timestamp the operation `id` is ready at, `1` once it is done, or `0` if it is
+    /// unknown or cancelled.
+    pub fn get_timestamp(&self, id: B256) -> U256 {
+        self.timestamps.get(id)
+    }
+
+    /// Gets the state of the operation `id`: 0 unset, 1 waiting, 2 ready or 3 done.
+    pub fn get_operation_state(&self, id: B256) -> u8 {
+        self.operation_state(id) as u8
+    }
+
+    /// Gets who last updated the number, and the block number and timestamp they did so at. As
+    /// on Arbitrum's EVM, the block number is an estimate of the L1 block number.
+    pub fn last_update(&self) -> (Address, u64, u64) {
+        (
+            self.last_updater.get(),
+            self.last_updated_block.get().to(),
+            self.last_updated_at.get().to(),
+        )
     }
 
     /// Increments `number` and updates its value in storage.
-    pub fn increment(&mut self) {
+    pub fn increment(&mut self) -> Result<(), Vec<u8>> {
         let number = self.number.get();
-        self.set_number(number + U256::from(1));
+        self.set_number(number + U256::from(1))
+    }
+
+    /// Increments the transient number and returns it. It starts from zero in every transaction.
+    pub fn transient_increment(&mut self) -> U256 {
+        transient::increment(COUNTER_TRANSIENT_LOCATION)
+    }
+
+    /// Gets the transient number, as incremented so far in this transaction.
+    pub fn transient_number(&self) -> U256 {
+        transient::load(COUNTER_TRANSIENT_LOCATION)
+    }
+
+    /// Runs each of `data` as a call to one of these methods, in order and within this call, and
+    /// returns their outputs, like OpenZeppelin's `Multicall`. The first call to fail reverts
+    /// the whole batch with its error. The caller stays the sender of every call.
+    pub fn multicall(&mut self, data: Vec<Bytes>) -> Result<Vec<Bytes>, Vec<u8>> {
+        let mut results = Vec::with_capacity(data.len());
+        for call in data {
+            results.push(route_call(self, &call)?.into());
+        }
+        Ok(results)
     }
 }
@@ -0,0 +1,101 @@
+//! EIP-1153 transient storage, which is cleared at the end of every transaction.
+//!
+//! The SDK's storage types are all persistent, so [`load`] and [`store`] call the host functions
+//! behind `TLOAD` and `TSTORE` directly. The SDK has no host to run them against outside the VM,
+//! so the tests below stand in a map for them, which they clear to end a transaction.
+
+use stylus_sdk::alloy_primitives::{B256, U256};
+
+#[cfg(not(test))]
+#[link(wasm_import_module = "vm_hooks")]
+extern "C" {
+    /// Reads the 32-byte value at `key` in transient storage into `dest`.
+    fn transient_load_bytes32(key: *const u8, dest: *mut u8);
+
+    /// Writes the 32-byte `value` at `key` in transient storage.
+    fn transient_store_bytes32(key: *const u8, value: *const u8);
+}
+
+#[cfg(test)]
+use host::{transient_load_bytes32, transient_store_bytes32};
+
+/// Reads the transient word at `key`, which is zero until it is stored in the same transaction.
+pub fn load(key: U256) -> U256 {
+    let key = B256::from(key);
+    let mut value = B256::ZERO;
+    unsafe { transient_load_bytes32(key.as_ptr(), value.as_mut_ptr()) };
+    value.into()
+}
+
+/// Writes the transient word at `key`, until the end of the transaction.
+pub fn store(key: U256, value: U256) {
+    let key = B256::from(key);
+    let value = B256::from(value);
+    unsafe { transient_store_bytes32(key.as_ptr(), value.as_ptr()) };
+}
+
+/// Adds one to the transient word at `key` and returns it.
+pub fn increment(key: U256) -> U256 {
+    let number = load(key) + U256::from(1);
+    store(key, number);
+    number
+}
+
+/// Transient storage as the VM keeps it for one thread's transactions.
+#[cfg(test)]
+mod host {
+    use std::{cell::RefCell, collections::HashMap};
+    use stylus_sdk::alloy_primitives::B256;
+
+    thread_local! {
+        static TRANSIENT: RefCell<HashMap<B256, B256>> = RefCell::new(HashMap::new());
+    }
+
+    /// Reads 32 bytes at `ptr`.
+    unsafe fn read(ptr: *const u8) -> B256 {
+        B256::from_slice(std::slice::from_raw_parts(ptr, 32))
+    }
+
+    pub unsafe fn transient_load_bytes32(key: *const u8, dest: *mut u8) {
+        let value = TRANSIENT.with(|words| words.borrow().get(&read(key)).copied());
+        let value = value.unwrap_or_default();
+        std::ptr::copy_nonoverlapping(value.as_ptr(), dest, 32);
+    }
+
+    pub unsafe fn transient_store_bytes32(key: *const u8, value: *const u8) {
+        let (key, value) = (read(key), read(value));
+        TRANSIENT.with(|words| words.borrow_mut().insert(key, value));
+    }
+
+    /// Clears transient storage, as the VM does once a transaction is over.
+    pub fn end_transaction() {
+        TRANSIENT.with(|words| words.borrow_mut().clear());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::COUNTER_TRANSIENT_LOCATION;
+
+    #[test]
+    fn accumulates_within_a_transaction_and_resets_after() {
+        for _ in 0..3 {
+            assert_eq!(U256::ZERO, load(COUNTER_TRANSIENT_LOCATION));
+            assert_eq!(U256::from(1), increment(COUNTER_TRANSIENT_LOCATION));
+            assert_eq!(U256::from(2), increment(COUNTER_TRANSIENT_LOCATION));
+            assert_eq!(U256::from(2), load(COUNTER_TRANSIENT_LOCATION));
+            host::end_transaction();
+        }
+    }
+
+    #[test]
+    fn keys_are_independent() {
+        let other = COUNTER_TRANSIENT_LOCATION + U256::from(1);
+        store(other, U256::from(7));
+        assert_eq!(U256::from(1), increment(COUNTER_TRANSIENT_LOCATION));
+        assert_eq!(U256::from(7), load(other));
+        host::end_transaction();
+        assert_eq!(U256::ZERO, load(other));
+    }
+}
@@ -0,0 +1,177 @@
+//! Example on how to interact with a deployed `elementary-return-types` program using defaults.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! Then, it sets each value via a tx, reads it back, mutates it via another tx and checks the
+//! value again. This is repeated for fixed-size bytes, address, bool, string and bytes.
+//! The deployed program is fully written in Rust and compiled to WASM but with Stylus,
+//! it is accessible just as a normal Solidity smart contract is via an ABI.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function getBytes1() external view returns (bytes1)
+            function setBytes1(bytes1 value) external
+            function invertBytes1() external
+
+            function getBytes4() external view returns (bytes4)
+            function setBytes4(bytes4 value) external
+            function invertBytes4() external
+
+            function getBytes20() external view returns (bytes20)
+            function setBytes20(bytes20 value) external
+            function invertBytes20() external
+
+            function getBytes31() external view returns (bytes31)
+            function setBytes31(bytes31 value) external
+            function invertBytes31() external
+
+            function getBytes32() external view returns (bytes32)
+            function setBytes32(bytes32 value) external
+            function invertBytes32() external
+
+            function getAddress() external view returns (address)
+            function setAddress(address value) external
+            function setAddressToSender() external
+
+            function getBool() external view returns (bool)
+            function setBool(bool value) external
+            function toggleBool() external
+
+            function getString() external view returns (string)
+            function setString(string value) external
+            function appendString(string value) external
+
+            function getBytes() external view returns (bytes)
+            function setBytes(bytes value) external
+            function appendBytes(bytes value) external
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // bytes1
+    counter.set_bytes1([0x0f]).send().await?.await?;
+    assert_eq!([0x0f], counter.get_bytes1().call().await?);
+    counter.invert_bytes1().send().await?.await?;
+    assert_eq!([0xf0], counter.get_bytes1().call().await?);
+    println!("Successfully round tripped bytes1");
+
+    // bytes4
+    let value = [0xde, 0xad, 0xbe, 0xef];
+    counter.set_bytes4(value).send().await?.await?;
+    assert_eq!(value, counter.get_bytes4().call().await?);
+    counter.invert_bytes4().send().await?.await?;
+    assert_eq!(value.map(|b| !b), counter.get_bytes4().call().await?);
+    println!("Successfully round tripped bytes4");
+
+    // bytes20
+    let value: [u8; 20] = std::array::from_fn(|i| i as u8);
+    counter.set_bytes20(value).send().await?.await?;
+    assert_eq!(value, counter.get_bytes20().call().await?);
+    counter.invert_bytes20().send().await?.await?;
+    assert_eq!(value.map(|b| !b), counter.get_bytes20().call().await?);
+    println!("Successfully round tripped bytes20");
+
+    // bytes31
+    let value: [u8; 31] = std::array::from_fn(|i| 0xff - i as u8);
+    counter.set_bytes31(value).send().await?.await?;
+    assert_eq!(value, counter.get_bytes31().call().await?);
+    counter.invert_bytes31().send().await?.await?;
+    assert_eq!(value.map(|b| !b), counter.get_bytes31().call().await?);
+    println!("Successfully round tripped bytes31");
+
+    // bytes32
+    let value = [0xaa; 32];
+    counter.set_bytes32(value).send().await?.await?;
+    assert_eq!(value, counter.get_bytes32().call().await?);
+    counter.invert_bytes32().send().await?.await?;
+    assert_eq!([0x55; 32], counter.get_bytes32().call().await?);
+    println!("Successfully round tripped bytes32");
+
+    // address
+    let value = Address::repeat_byte(0x42);
+    counter.set_address(value).send().await?.await?;
+    assert_eq!(value, counter.get_address().call().await?);
+    counter.set_address_to_sender().send().await?.await?;
+    assert_eq!(wallet.address(), counter.get_address().call().await?);
+    println!("Successfully round tripped address");
+
+    // bool
+    counter.set_bool(true).send().await?.await?;
+    assert!(counter.get_bool().call().await?);
+    counter.toggle_bool().send().await?.await?;
+    assert!(!counter.get_bool().call().await?);
+    println!("Successfully round tripped bool");
+
+    // string, long enough to spill out of a single slot
+    let value = "Hello from Stylus, this string is longer than thirty-one bytes";
+    counter.set_string(value.to_string()).send().await?.await?;
+    assert_eq!(value, counter.get_string().call().await?);
+    counter.append_string("!".to_string()).send().await?.await?;
+    assert_eq!(format!("{value}!"), counter.get_string().call().await?);
+    counter.set_string(String::new()).send().await?.await?;
+    assert_eq!("", counter.get_string().call().await?);
+    println!("Successfully round tripped string");
+
+    // bytes, growing from a short to a long encoding
+    let value = Bytes::from(vec![0x01; 31]);
+    counter.set_bytes(value.clone()).send().await?.await?;
+    assert_eq!(value, counter.get_bytes().call().await?);
+    counter
+        .append_bytes(Bytes::from(vec![0x02, 0x03]))
+        .send()
+        .await?
+        .await?;
+    let mut expected = vec![0x01; 31];
+    expected.extend([0x02, 0x03]);
+    assert_eq!(Bytes::from(expected), counter.get_bytes().call().await?);
+    println!("Successfully round tripped bytes");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
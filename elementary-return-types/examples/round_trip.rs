@@ -0,0 +1,120 @@
+//! Example on how `elementary-return-types` encodes and decodes its argument and return types.
+//! This example runs offline: it encodes values of every type the program takes and returns
+//! with the same `AbiType` encoding its router uses, checks the result against ethers-rs'
+//! independent encoder, and decodes it back with the router's validation. Calldata with dirty
+//! padding, which Solidity rejects, must fail to decode.
+
+use alloy_sol_types::SolType;
+use ethers::abi::{encode, Token};
+use eyre::eyre;
+use stylus_sdk::{
+    abi::{AbiType, Bytes},
+    alloy_primitives::{Address, FixedBytes},
+};
+
+/// The bytes `bytesN` values are cut from.
+const PATTERN: [u8; 32] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+    0xa5, 0x5a, 0xa5, 0x5a, 0xff, 0x00, 0xff, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+];
+
+/// Encodes `$value` as the parameters `$ty`, checks the encoding against ethers-rs encoding
+/// `$token`s, and that decoding it back with validation encodes to the same bytes.
+macro_rules! round_trip {
+    ($ty:ty, $value:expr, [$($token:expr),* $(,)?]) => {{
+        type Params = <$ty as AbiType>::SolType;
+        let encoded = Params::abi_encode_params(&$value);
+        assert_eq!(encode(&[$($token),*]), encoded, "{}", stringify!($ty));
+        let decoded = Params::abi_decode_params(&encoded, true)
+            .map_err(|err| eyre!("{} doesn't decode: {err}", stringify!($ty)))?;
+        assert_eq!(encoded, Params::abi_encode_params(&decoded), "{}", stringify!($ty));
+        println!("{} round trips", stringify!($ty));
+    }};
+}
+
+/// Round trips a `bytesN` for each of the widths `$n`.
+macro_rules! round_trip_fixed {
+    ($($n:literal),*) => {$(
+        round_trip!(
+            (FixedBytes<$n>,),
+            (FixedBytes::<$n>::from_slice(&PATTERN[..$n]),),
+            [Token::FixedBytes(PATTERN[..$n].to_vec())]
+        );
+    )*};
+}
+
+/// Checks that the hex `$word` fails to decode as the parameters `$ty`.
+macro_rules! rejects {
+    ($ty:ty, $word:expr) => {{
+        type Params = <$ty as AbiType>::SolType;
+        let word = hex::decode($word)?;
+        assert!(
+            Params::abi_decode_params(&word, true).is_err(),
+            "{} accepted dirty {}",
+            stringify!($ty),
+            $word
+        );
+        println!("{} rejects dirty {}", stringify!($ty), $word);
+    }};
+}
+
+fn main() -> eyre::Result<()> {
+    round_trip_fixed!(
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        26, 27, 28, 29, 30, 31, 32
+    );
+
+    let addr = Address::repeat_byte(0x42);
+    let token = Token::Address(ethers::types::Address::repeat_byte(0x42));
+    round_trip!((Address,), (addr,), [token.clone()]);
+    round_trip!((bool,), (true,), [Token::Bool(true)]);
+    round_trip!((bool,), (false,), [Token::Bool(false)]);
+
+    // Dynamic types, empty and spilling over a 32-byte word.
+    for text in [
+        "",
+        "hello",
+        "héllo wörld, long enough to need a second word",
+    ] {
+        round_trip!(
+            (String,),
+            (text.to_string(),),
+            [Token::String(text.to_string())]
+        );
+    }
+    for data in [vec![], vec![0xab; 31], vec![0xcd; 33]] {
+        round_trip!((Bytes,), (Bytes::from(data.clone()),), [Token::Bytes(data)]);
+    }
+
+    // Static and dynamic types together, so heads and tails must line up.
+    round_trip!(
+        (Address, bool, String, Bytes),
+        (addr, true, "text".to_string(), Bytes::from(vec![1, 2, 3])),
+        [
+            token,
+            Token::Bool(true),
+            Token::String("text".to_string()),
+            Token::Bytes(vec![1, 2, 3]),
+        ]
+    );
+
+    // Padding must be zero, and a bool must be 0 or 1.
+    rejects!(
+        (FixedBytes<4>,),
+        "0123456700000000000000000000000000000000000000000000000000000001"
+    );
+    rejects!(
+        (FixedBytes<31>,),
+        "0123456789abcdeffedcba9876543210a55aa55aff00ff00112233445566770f"
+    );
+    rejects!(
+        (Address,),
+        "0000000000000000000000014242424242424242424242424242424242424242"
+    );
+    rejects!(
+        (bool,),
+        "0000000000000000000000000000000000000000000000000000000000000002"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,534 @@
+//! Stylus Elementary Return Types Test Contract
+//!
+//! Stores, returns and mutates every elementary Solidity type that is not an integer:
+//! `bytes1` to `bytes32`, `address`, `bool`, `string` and `bytes`.
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+/// Use an efficient WASM allocator.
+#[global_allocator]
+static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
+
+use alloc::string::String;
+use alloy_primitives::FixedBytes;
+/// Import items from the SDK. The prelude contains common traits and macros.
+use stylus_sdk::{abi::Bytes, alloy_primitives::Address, msg, prelude::*};
+
+// Define some persistent storage using the Solidity ABI.
+// `Counter` will be the entrypoint.
+sol_storage! {
+    #[entrypoint]
+    pub struct Counter {
+        bytes1 fixed1;
+        bytes2 fixed2;
+        bytes3 fixed3;
+        bytes4 fixed4;
+        bytes5 fixed5;
+        bytes6 fixed6;
+        bytes7 fixed7;
+        bytes8 fixed8;
+        bytes9 fixed9;
+        bytes10 fixed10;
+        bytes11 fixed11;
+        bytes12 fixed12;
+        bytes13 fixed13;
+        bytes14 fixed14;
+        bytes15 fixed15;
+        bytes16 fixed16;
+        bytes17 fixed17;
+        bytes18 fixed18;
+        bytes19 fixed19;
+        bytes20 fixed20;
+        bytes21 fixed21;
+        bytes22 fixed22;
+        bytes23 fixed23;
+        bytes24 fixed24;
+        bytes25 fixed25;
+        bytes26 fixed26;
+        bytes27 fixed27;
+        bytes28 fixed28;
+        bytes29 fixed29;
+        bytes30 fixed30;
+        bytes31 fixed31;
+        bytes32 fixed32;
+        address addr;
+        bool flag;
+        string text;
+        bytes data;
+    }
+}
+
+/// Declare that `Counter` is a contract with the following external methods.
+#[external]
+impl Counter {
+    pub fn get_bytes1(&self) -> FixedBytes<1> {
+        self.fixed1.get()
+    }
+
+    pub fn set_bytes1(&mut self, value: FixedBytes<1>) {
+        self.fixed1.set(value)
+    }
+
+    pub fn invert_bytes1(&mut self) {
+        let current = self.fixed1.get();
+        self.fixed1.set(!current);
+    }
+
+    pub fn get_bytes2(&self) -> FixedBytes<2> {
+        self.fixed2.get()
+    }
+
+    pub fn set_bytes2(&mut self, value: FixedBytes<2>) {
+        self.fixed2.set(value)
+    }
+
+    pub fn invert_bytes2(&mut self) {
+        let current = self.fixed2.get();
+        self.fixed2.set(!current);
+    }
+
+    pub fn get_bytes3(&self) -> FixedBytes<3> {
+        self.fixed3.get()
+    }
+
+    pub fn set_bytes3(&mut self, value: FixedBytes<3>) {
+        self.fixed3.set(value)
+    }
+
+    pub fn invert_bytes3(&mut self) {
+        let current = self.fixed3.get();
+        self.fixed3.set(!current);
+    }
+
+    pub fn get_bytes4(&self) -> FixedBytes<4> {
+        self.fixed4.get()
+    }
+
+    pub fn set_bytes4(&mut self, value: FixedBytes<4>) {
+        self.fixed4.set(value)
+    }
+
+    pub fn invert_bytes4(&mut self) {
+        let current = self.fixed4.get();
+        self.fixed4.set(!current);
+    }
+
+    pub fn get_bytes5(&self) -> FixedBytes<5> {
+        self.fixed5.get()
+    }
+
+    pub fn set_bytes5(&mut self, value: FixedBytes<5>) {
+        self.fixed5.set(value)
+    }
+
+    pub fn invert_bytes5(&mut self) {
+        let current = self.fixed5.get();
+        self.fixed5.set(!current);
+    }
+
+    pub fn get_bytes6(&self) -> FixedBytes<6> {
+        self.fixed6.get()
+    }
+
+    pub fn set_bytes6(&mut self, value: FixedBytes<6>) {
+        self.fixed6.set(value)
+    }
+
+    pub fn invert_bytes6(&mut self) {
+        let current = self.fixed6.get();
+        self.fixed6.set(!current);
+    }
+
+    pub fn get_bytes7(&self) -> FixedBytes<7> {
+        self.fixed7.get()
+    }
+
+    pub fn set_bytes7(&mut self, value: FixedBytes<7>) {
+        self.fixed7.set(value)
+    }
+
+    pub fn invert_bytes7(&mut self) {
+        let current = self.fixed7.get();
+        self.fixed7.set(!current);
+    }
+
+    pub fn get_bytes8(&self) -> FixedBytes<8> {
+        self.fixed8.get()
+    }
+
+    pub fn set_bytes8(&mut self, value: FixedBytes<8>) {
+        self.fixed8.set(value)
+    }
+
+    pub fn invert_bytes8(&mut self) {
+        let current = self.fixed8.get();
+        self.fixed8.set(!current);
+    }
+
+    pub fn get_bytes9(&self) -> FixedBytes<9> {
+        self.fixed9.get()
+    }
+
+    pub fn set_bytes9(&mut self, value: FixedBytes<9>) {
+        self.fixed9.set(value)
+    }
+
+    pub fn invert_bytes9(&mut self) {
+        let current = self.fixed9.get();
+        self.fixed9.set(!current);
+    }
+
+    pub fn get_bytes10(&self) -> FixedBytes<10> {
+        self.fixed10.get()
+    }
+
+    pub fn set_bytes10(&mut self, value: FixedBytes<10>) {
+        self.fixed10.set(value)
+    }
+
+    pub fn invert_bytes10(&mut self) {
+        let current = self.fixed10.get();
+        self.fixed10.set(!current);
+    }
+
+    pub fn get_bytes11(&self) -> FixedBytes<11> {
+        self.fixed11.get()
+    }
+
+    pub fn set_bytes11(&mut self, value: FixedBytes<11>) {
+        self.fixed11.set(value)
+    }
+
+    pub fn invert_bytes11(&mut self) {
+        let current = self.fixed11.get();
+        self.fixed11.set(!current);
+    }
+
+    pub fn get_bytes12(&self) -> FixedBytes<12> {
+        self.fixed12.get()
+    }
+
+    pub fn set_bytes12(&mut self, value: FixedBytes<12>) {
+        self.fixed12.set(value)
+    }
+
+    pub fn invert_bytes12(&mut self) {
+        let current = self.fixed12.get();
+        self.fixed12.set(!current);
+    }
+
+    pub fn get_bytes13(&self) -> FixedBytes<13> {
+        self.fixed13.get()
+    }
+
+    pub fn set_bytes13(&mut self, value: FixedBytes<13>) {
+        self.fixed13.set(value)
+    }
+
+    pub fn invert_bytes13(&mut self) {
+        let current = self.fixed13.get();
+        self.fixed13.set(!current);
+    }
+
+    pub fn get_bytes14(&self) -> FixedBytes<14> {
+        self.fixed14.get()
+    }
+
+    pub fn set_bytes14(&mut self, value: FixedBytes<14>) {
+        self.fixed14.set(value)
+    }
+
+    pub fn invert_bytes14(&mut self) {
+        let current = self.fixed14.get();
+        self.fixed14.set(!current);
+    }
+
+    pub fn get_bytes15(&self) -> FixedBytes<15> {
+        self.fixed15.get()
+    }
+
+    pub fn set_bytes15(&mut self, value: FixedBytes<15>) {
+        self.fixed15.set(value)
+    }
+
+    pub fn invert_bytes15(&mut self) {
+        let current = self.fixed15.get();
+        self.fixed15.set(!current);
+    }
+
+    pub fn get_bytes16(&self) -> FixedBytes<16> {
+        self.fixed16.get()
+    }
+
+    pub fn set_bytes16(&mut self, value: FixedBytes<16>) {
+        self.fixed16.set(value)
+    }
+
+    pub fn invert_bytes16(&mut self) {
+        let current = self.fixed16.get();
+        self.fixed16.set(!current);
+    }
+
+    pub fn get_bytes17(&self) -> FixedBytes<17> {
+        self.fixed17.get()
+    }
+
+    pub fn set_bytes17(&mut self, value: FixedBytes<17>) {
+        self.fixed17.set(value)
+    }
+
+    pub fn invert_bytes17(&mut self) {
+        let current = self.fixed17.get();
+        self.fixed17.set(!current);
+    }
+
+    pub fn get_bytes18(&self) -> FixedBytes<18> {
+        self.fixed18.get()
+    }
+
+    pub fn set_bytes18(&mut self, value: FixedBytes<18>) {
+        self.fixed18.set(value)
+    }
+
+    pub fn invert_bytes18(&mut self) {
+        let current = self.fixed18.get();
+        self.fixed18.set(!current);
+    }
+
+    pub fn get_bytes19(&self) -> FixedBytes<19> {
+        self.fixed19.get()
+    }
+
+    pub fn set_bytes19(&mut self, value: FixedBytes<19>) {
+        self.fixed19.set(value)
+    }
+
+    pub fn invert_bytes19(&mut self) {
+        let current = self.fixed19.get();
+        self.fixed19.set(!current);
+    }
+
+    pub fn get_bytes20(&self) -> FixedBytes<20> {
+        self.fixed20.get()
+    }
+
+    pub fn set_bytes20(&mut self, value: FixedBytes<20>) {
+        self.fixed20.set(value)
+    }
+
+    pub fn invert_bytes20(&mut self) {
+        let current = self.fixed20.get();
+        self.fixed20.set(!current);
+    }
+
+    pub fn get_bytes21(&self) -> FixedBytes<21> {
+        self.fixed21.get()
+    }
+
+    pub fn set_bytes21(&mut self, value: FixedBytes<21>) {
+        self.fixed21.set(value)
+    }
+
+    pub fn invert_bytes21(&mut self) {
+        let current = self.fixed21.get();
+        self.fixed21.set(!current);
+    }
+
+    pub fn get_bytes22(&self) -> FixedBytes<22> {
+        self.fixed22.get()
+    }
+
+    pub fn set_bytes22(&mut self, value: FixedBytes<22>) {
+        self.fixed22.set(value)
+    }
+
+    pub fn invert_bytes22(&mut self) {
+        let current = self.fixed22.get();
+        self.fixed22.set(!current);
+    }
+
+    pub fn get_bytes23(&self) -> FixedBytes<23> {
+        self.fixed23.get()
+    }
+
+    pub fn set_bytes23(&mut self, value: FixedBytes<23>) {
+        self.fixed23.set(value)
+    }
+
+    pub fn invert_bytes23(&mut self) {
+        let current = self.fixed23.get();
+        self.fixed23.set(!current);
+    }
+
+    pub fn get_bytes24(&self) -> FixedBytes<24> {
+        self.fixed24.get()
+    }
+
+    pub fn set_bytes24(&mut self, value: FixedBytes<24>) {
+        self.fixed24.set(value)
+    }
+
+    pub fn invert_bytes24(&mut self) {
+        let current = self.fixed24.get();
+        self.fixed24.set(!current);
+    }
+
+    pub fn get_bytes25(&self) -> FixedBytes<25> {
+        self.fixed25.get()
+    }
+
+    pub fn set_bytes25(&mut self, value: FixedBytes<25>) {
+        self.fixed25.set(value)
+    }
+
+    pub fn invert_bytes25(&mut self) {
+        let current = self.fixed25.get();
+        self.fixed25.set(!current);
+    }
+
+    pub fn get_bytes26(&self) -> FixedBytes<26> {
+        self.fixed26.get()
+    }
+
+    pub fn set_bytes26(&mut self, value: FixedBytes<26>) {
+        self.fixed26.set(value)
+    }
+
+    pub fn invert_bytes26(&mut self) {
+        let current = self.fixed26.get();
+        self.fixed26.set(!current);
+    }
+
+    pub fn get_bytes27(&self) -> FixedBytes<27> {
+        self.fixed27.get()
+    }
+
+    pub fn set_bytes27(&mut self, value: FixedBytes<27>) {
+        self.fixed27.set(value)
+    }
+
+    pub fn invert_bytes27(&mut self) {
+        let current = self.fixed27.get();
+        self.fixed27.set(!current);
+    }
+
+    pub fn get_bytes28(&self) -> FixedBytes<28> {
+        self.fixed28.get()
+    }
+
+    pub fn set_bytes28(&mut self, value: FixedBytes<28>) {
+        self.fixed28.set(value)
+    }
+
+    pub fn invert_bytes28(&mut self) {
+        let current = self.fixed28.get();
+        self.fixed28.set(!current);
+    }
+
+    pub fn get_bytes29(&self) -> FixedBytes<29> {
+        self.fixed29.get()
+    }
+
+    pub fn set_bytes29(&mut self, value: FixedBytes<29>) {
+        self.fixed29.set(value)
+    }
+
+    pub fn invert_bytes29(&mut self) {
+        let current = self.fixed29.get();
+        self.fixed29.set(!current);
+    }
+
+    pub fn get_bytes30(&self) -> FixedBytes<30> {
+        self.fixed30.get()
+    }
+
+    pub fn set_bytes30(&mut self, value: FixedBytes<30>) {
+        self.fixed30.set(value)
+    }
+
+    pub fn invert_bytes30(&mut self) {
+        let current = self.fixed30.get();
+        self.fixed30.set(!current);
+    }
+
+    pub fn get_bytes31(&self) -> FixedBytes<31> {
+        self.fixed31.get()
+    }
+
+    pub fn set_bytes31(&mut self, value: FixedBytes<31>) {
+        self.fixed31.set(value)
+    }
+
+    pub fn invert_bytes31(&mut self) {
+        let current = self.fixed31.get();
+        self.fixed31.set(!current);
+    }
+
+    pub fn get_bytes32(&self) -> FixedBytes<32> {
+        self.fixed32.get()
+    }
+
+    pub fn set_bytes32(&mut self, value: FixedBytes<32>) {
+        self.fixed32.set(value)
+    }
+
+    pub fn invert_bytes32(&mut self) {
+        let current = self.fixed32.get();
+        self.fixed32.set(!current);
+    }
+
+    pub fn get_address(&self) -> Address {
+        self.addr.get()
+    }
+
+    pub fn set_address(&mut self, value: Address) {
+        self.addr.set(value)
+    }
+
+    pub fn set_address_to_sender(&mut self) {
+        self.addr.set(msg::sender())
+    }
+
+    pub fn get_bool(&self) -> bool {
+        self.flag.get()
+    }
+
+    pub fn set_bool(&mut self, value: bool) {
+        self.flag.set(value)
+    }
+
+    pub fn toggle_bool(&mut self) {
+        let current = self.flag.get();
+        self.flag.set(!current);
+    }
+
+    pub fn get_string(&self) -> String {
+        self.text.get_string()
+    }
+
+    pub fn set_string(&mut self, value: String) {
+        self.text.set_str(value)
+    }
+
+    pub fn append_string(&mut self, value: String) {
+        let mut current = self.text.get_string();
+        current.push_str(&value);
+        self.text.set_str(current);
+    }
+
+    pub fn get_bytes(&self) -> Bytes {
+        self.data.get_bytes().into()
+    }
+
+    pub fn set_bytes(&mut self, value: Bytes) {
+        self.data.set_bytes(value.as_slice())
+    }
+
+    pub fn append_bytes(&mut self, value: Bytes) {
+        for byte in value.iter() {
+            self.data.push(*byte);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Example on how to configure a deployed `int-return-types` program in a single transaction.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It sets every integer field at once with `setAll`, reads them back with a single `getAll`
+//! call and asserts the struct round trips, including the odd-width and negative members.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, I256, U256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct AllInts { int8 signed8; int16 signed16; int24 signed24; int32 signed32; int64 signed64; int128 signed128; int160 signed160; int200 signed200; int256 signed256; uint8 unsigned8; uint16 unsigned16; uint24 unsigned24; uint32 unsigned32; uint64 unsigned64; uint128 unsigned128; uint160 unsigned160; uint200 unsigned200; uint256 unsigned256; }
+            function getAll() external view returns (AllInts)
+            function setAll(AllInts values) external
+            function getI24() external view returns (int24)
+            function getU200() external view returns (uint200)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    let values = AllInts {
+        signed8: i8::MIN,
+        signed16: -16,
+        signed24: -8_388_608,
+        signed32: -32,
+        signed64: -64,
+        signed128: i128::MIN,
+        signed160: I256::from(-160),
+        signed200: I256::from(-200),
+        signed256: I256::MIN,
+        unsigned8: u8::MAX,
+        unsigned16: 16,
+        unsigned24: 16_777_215,
+        unsigned32: 32,
+        unsigned64: u64::MAX,
+        unsigned128: 128,
+        unsigned160: U256::from(160),
+        unsigned200: (U256::one() << 200) - 1,
+        unsigned256: U256::MAX,
+    };
+
+    let pending = counter.set_all(values.clone());
+    if let Some(receipt) = pending.send().await?.await? {
+        println!("Receipt = {:?}", receipt);
+    }
+    println!("Successfully set every field via a single tx");
+
+    let all = counter.get_all().call().await?;
+    println!("Counter values = {:?}", all);
+    assert_eq!(values, all);
+
+    // The struct members land in the same storage as the per-width setters.
+    assert_eq!(-8_388_608, counter.get_i24().call().await?);
+    assert_eq!((U256::one() << 200) - 1, counter.get_u200().call().await?);
+    println!("Successfully read every field back via a single call");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,284 @@
+//! Example on how to use a deployed `int-return-types` program as an oracle for integer semantics.
+//! This example uses ethers-rs to call the pure arithmetic entrypoints of the program and
+//! asserts every result against the table of expected Solidity 0.8 results below.
+//! The calls are `eth_call`s only, so no private key is needed.
+
+use ethers::{
+    abi::{parse_abi, Abi, ParamType, Token},
+    providers::{Http, Middleware, MiddlewareError, Provider},
+    types::{Address, Bytes, TransactionRequest, I256, U256},
+};
+use eyre::eyre;
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Selector of Solidity's `Panic(uint256)` error.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// What a call is expected to return.
+enum Expected {
+    /// The call returns this integer, in decimal.
+    Value(&'static str),
+    /// The call returns this boolean.
+    Bool(bool),
+    /// The call reverts with `Panic(code)`.
+    Panic(u64),
+}
+
+use Expected::*;
+
+/// Panic code for an arithmetic underflow or overflow.
+const OVERFLOW: u64 = 0x11;
+
+/// Panic code for a division or modulo by zero.
+const DIVISION_BY_ZERO: u64 = 0x12;
+
+const I8_MIN: &str = "-128";
+const I8_MAX: &str = "127";
+const I24_MIN: &str = "-8388608";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+const U200_MAX: &str = "1606938044258990275541962092341162602522202993782792835301375";
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+/// Expected results, as computed by `solc` 0.8 for the same expressions. Solidity's `>>` on a
+/// signed type is arithmetic, so `sarI*` is `a >> shift` while the logical `shrI*` is
+/// `intN(uintN(a) >> shift)`.
+const CASES: &[(&str, &[&str], Expected)] = &[
+    // addition and subtraction
+    ("addI8", &["100", "27"], Value("127")),
+    ("addI8", &["100", "28"], Panic(OVERFLOW)),
+    ("addI8", &[I8_MIN, "-1"], Panic(OVERFLOW)),
+    ("subI8", &["-100", "28"], Value(I8_MIN)),
+    ("subI8", &["-100", "29"], Panic(OVERFLOW)),
+    ("subI8", &["0", I8_MIN], Panic(OVERFLOW)),
+    ("addU8", &["255", "1"], Panic(OVERFLOW)),
+    ("subU8", &["0", "1"], Panic(OVERFLOW)),
+    ("addI24", &[I24_MIN, "8388607"], Value("-1")),
+    ("addU200", &[U200_MAX, "1"], Panic(OVERFLOW)),
+    ("addI256", &[I256_MAX, "1"], Panic(OVERFLOW)),
+    ("subI256", &[I256_MIN, "1"], Panic(OVERFLOW)),
+    ("addU256", &[U256_MAX, "0"], Value(U256_MAX)),
+    ("addU256", &[U256_MAX, "1"], Panic(OVERFLOW)),
+    // multiplication
+    ("mulI8", &["-16", "8"], Value(I8_MIN)),
+    ("mulI8", &["16", "8"], Panic(OVERFLOW)),
+    ("mulI8", &[I8_MIN, "-1"], Panic(OVERFLOW)),
+    ("mulU8", &["16", "16"], Panic(OVERFLOW)),
+    ("mulI256", &[I256_MIN, "-1"], Panic(OVERFLOW)),
+    ("mulI256", &[I256_MIN, "1"], Value(I256_MIN)),
+    // division rounds toward zero, `MIN / -1` overflows
+    ("divI8", &["7", "2"], Value("3")),
+    ("divI8", &["-7", "2"], Value("-3")),
+    ("divI8", &["7", "-2"], Value("-3")),
+    ("divI8", &["-7", "-2"], Value("3")),
+    ("divI8", &[I8_MIN, "-1"], Panic(OVERFLOW)),
+    ("divI8", &["1", "0"], Panic(DIVISION_BY_ZERO)),
+    ("divU8", &["255", "2"], Value("127")),
+    ("divU8", &["1", "0"], Panic(DIVISION_BY_ZERO)),
+    ("divI24", &[I24_MIN, "-1"], Panic(OVERFLOW)),
+    ("divI256", &[I256_MIN, "-1"], Panic(OVERFLOW)),
+    (
+        "divI256",
+        &[I256_MIN, "2"],
+        Value("-28948022309329048855892746252171976963317496166410141009864396001978282409984"),
+    ),
+    // modulo takes the sign of the dividend
+    ("modI8", &["7", "3"], Value("1")),
+    ("modI8", &["-7", "3"], Value("-1")),
+    ("modI8", &["7", "-3"], Value("1")),
+    ("modI8", &["-7", "-3"], Value("-1")),
+    ("modI8", &[I8_MIN, "-1"], Value("0")),
+    ("modI8", &["1", "0"], Panic(DIVISION_BY_ZERO)),
+    ("modU8", &["255", "16"], Value("15")),
+    ("modU8", &["1", "0"], Panic(DIVISION_BY_ZERO)),
+    ("modI256", &[I256_MIN, "-1"], Value("0")),
+    // exponentiation
+    ("expI8", &["2", "6"], Value("64")),
+    ("expI8", &["2", "7"], Panic(OVERFLOW)),
+    ("expI8", &["-2", "7"], Value(I8_MIN)),
+    ("expI8", &["-1", "255"], Value("-1")),
+    ("expI8", &["0", "0"], Value("1")),
+    ("expU8", &["2", "8"], Panic(OVERFLOW)),
+    ("expU8", &["3", "5"], Value("243")),
+    (
+        "expU256",
+        &["2", "255"],
+        Value("57896044618658097711785492504343953926634992332820282019728792003956564819968"),
+    ),
+    ("expU256", &["2", "256"], Panic(OVERFLOW)),
+    // shifts never revert, shifting by the width or more clears every bit
+    ("shlI8", &["1", "7"], Value(I8_MIN)),
+    ("shlI8", &["-1", "4"], Value("-16")),
+    ("shlI8", &["1", "8"], Value("0")),
+    ("shlU8", &["255", "4"], Value("240")),
+    ("shlU8", &["1", U256_MAX], Value("0")),
+    // int8(uint8(a) >> shift)
+    ("shrI8", &["-1", "4"], Value("15")),
+    ("shrI8", &["-1", "8"], Value("0")),
+    // a >> shift
+    ("sarI8", &["-1", "4"], Value("-1")),
+    ("sarI8", &["-7", "1"], Value("-4")),
+    ("sarI8", &[I8_MIN, "7"], Value("-1")),
+    ("sarI8", &[I8_MIN, "200"], Value("-1")),
+    ("sarI8", &[I8_MAX, "200"], Value("0")),
+    ("shrU8", &["240", "4"], Value("15")),
+    ("shrU8", &["240", "8"], Value("0")),
+    ("sarI24", &[I24_MIN, "23"], Value("-1")),
+    ("sarI256", &[I256_MIN, "255"], Value("-1")),
+    // int256(uint256(a) >> shift)
+    ("shrI256", &["-1", "255"], Value("1")),
+    (
+        "shlU256",
+        &["1", "255"],
+        Value("57896044618658097711785492504343953926634992332820282019728792003956564819968"),
+    ),
+    ("shlU256", &["1", "256"], Value("0")),
+    // bitwise operators
+    ("andI8", &["-1", "85"], Value("85")),
+    ("orI8", &[I8_MIN, "1"], Value("-127")),
+    ("xorI8", &["-1", I8_MAX], Value(I8_MIN)),
+    ("notI8", &["0"], Value("-1")),
+    ("notI8", &[I8_MIN], Value(I8_MAX)),
+    ("andU8", &["240", "60"], Value("48")),
+    ("orU8", &["240", "15"], Value("255")),
+    ("xorU8", &["255", "15"], Value("240")),
+    ("notU8", &["0"], Value("255")),
+    ("notI24", &["0"], Value("-1")),
+    ("notU200", &["0"], Value(U200_MAX)),
+    ("notU256", &["0"], Value(U256_MAX)),
+    // comparisons
+    ("ltI8", &["-1", "0"], Bool(true)),
+    ("ltI8", &[I8_MIN, I8_MAX], Bool(true)),
+    ("gtI8", &["-1", "0"], Bool(false)),
+    ("eqI8", &["-1", "-1"], Bool(true)),
+    ("ltU8", &["0", "255"], Bool(true)),
+    ("gtU8", &["255", "0"], Bool(true)),
+    ("eqU8", &["1", "2"], Bool(false)),
+    ("ltI256", &[I256_MIN, I256_MAX], Bool(true)),
+    ("gtI256", &[I256_MIN, I256_MAX], Bool(false)),
+    ("ltU256", &["0", U256_MAX], Bool(true)),
+    ("neI8", &["-1", "-1"], Bool(false)),
+    ("neU8", &["1", "2"], Bool(true)),
+    ("leI8", &["-1", "-1"], Bool(true)),
+    ("leI8", &["0", "-1"], Bool(false)),
+    ("leI8", &[I8_MIN, I8_MAX], Bool(true)),
+    ("geI8", &["-1", "0"], Bool(false)),
+    ("geI8", &[I8_MAX, I8_MAX], Bool(true)),
+    ("leU8", &["255", "255"], Bool(true)),
+    ("geU8", &["0", "255"], Bool(false)),
+    ("leI256", &[I256_MAX, I256_MIN], Bool(false)),
+    ("geI256", &[I256_MIN, I256_MIN], Bool(true)),
+    ("neI256", &[I256_MIN, I256_MAX], Bool(true)),
+    ("leU256", &[U256_MAX, "0"], Bool(false)),
+    ("geU256", &[U256_MAX, U256_MAX], Bool(true)),
+];
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let abi = arithmetic_abi()?;
+
+    for (name, args, expected) in CASES {
+        let function = abi.function(name)?;
+        let tokens = function
+            .inputs
+            .iter()
+            .zip(args.iter())
+            .map(|(param, arg)| parse_token(&param.kind, arg))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let tx = TransactionRequest::new()
+            .to(address)
+            .data(function.encode_input(&tokens)?);
+        let result = provider.call(&tx.into(), None).await;
+
+        match (expected, result) {
+            (Value(value), Ok(output)) => {
+                let output = function.decode_output(&output)?;
+                let expected = parse_token(&function.outputs[0].kind, value)?;
+                assert_eq!(expected, output[0], "{name}({args:?})");
+            }
+            (Bool(value), Ok(output)) => {
+                let output = function.decode_output(&output)?;
+                assert_eq!(Token::Bool(*value), output[0], "{name}({args:?})");
+            }
+            (Panic(code), Err(err)) => {
+                let data: Bytes = err
+                    .as_error_response()
+                    .and_then(|err| err.as_revert_data())
+                    .ok_or_else(|| eyre!("{name}({args:?}) failed without revert data: {err}"))?;
+                assert_eq!(PANIC_SELECTOR, data[..4], "{name}({args:?})");
+                assert_eq!(U256::from(*code), U256::from_big_endian(&data[4..]));
+            }
+            (Panic(code), Ok(output)) => {
+                return Err(eyre!(
+                    "{name}({args:?}) returned {output}, expected Panic({code:#x})"
+                ));
+            }
+            (_, Err(err)) => return Err(eyre!("{name}({args:?}) reverted: {err}")),
+        }
+        println!("{name}({}) ok", args.join(", "));
+    }
+
+    println!("All {} cases match Solidity", CASES.len());
+    Ok(())
+}
+
+/// Builds the ABI of the pure arithmetic entrypoints for every width.
+fn arithmetic_abi() -> eyre::Result<Abi> {
+    let mut signatures = Vec::new();
+    for (prefix, sol) in [("I", "int"), ("U", "uint")] {
+        for bits in [8, 16, 24, 32, 64, 128, 160, 200, 256] {
+            let (name, ty) = (format!("{prefix}{bits}"), format!("{sol}{bits}"));
+            for op in ["add", "sub", "mul", "div", "mod", "and", "or", "xor"] {
+                signatures.push(format!(
+                    "function {op}{name}({ty} a, {ty} b) external pure returns ({ty})"
+                ));
+            }
+            let shifts: &[&str] = if sol == "int" {
+                &["shl", "shr", "sar"]
+            } else {
+                &["shl", "shr"]
+            };
+            for op in shifts {
+                signatures.push(format!(
+                    "function {op}{name}({ty} a, uint256 shift) external pure returns ({ty})"
+                ));
+            }
+            for op in ["lt", "gt", "eq", "ne", "le", "ge"] {
+                signatures.push(format!(
+                    "function {op}{name}({ty} a, {ty} b) external pure returns (bool)"
+                ));
+            }
+            signatures.push(format!(
+                "function exp{name}({ty} base, uint{bits} exponent) external pure returns ({ty})"
+            ));
+            signatures.push(format!(
+                "function not{name}({ty} a) external pure returns ({ty})"
+            ));
+        }
+    }
+    let signatures: Vec<&str> = signatures.iter().map(String::as_str).collect();
+    Ok(parse_abi(&signatures)?)
+}
+
+/// Parses a decimal argument into a token of the given ABI type.
+fn parse_token(kind: &ParamType, value: &str) -> eyre::Result<Token> {
+    match kind {
+        ParamType::Int(_) => Ok(Token::Int(I256::from_dec_str(value)?.into_raw())),
+        ParamType::Uint(_) => Ok(Token::Uint(U256::from_dec_str(value)?)),
+        _ => Err(eyre!("unsupported parameter type {kind}")),
+    }
+}
@@ -0,0 +1,146 @@
+//! Example on how arrays of odd-width integers round trip through a deployed `int-return-types` program.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It echoes `int8[]`, `uint24[4]`, `int256[][]` and `uint160[]` arguments back, then pushes,
+//! reads, slices and pops the storage vectors and checks the out-of-bounds reverts.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, I256, U256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function echoI8Array(int8[] values) external pure returns (int8[])
+            function echoU24FixedArray(uint24[4] values) external pure returns (uint24[4])
+            function echoI256NestedArray(int256[][] values) external pure returns (int256[][])
+            function echoU160Array(uint160[] values) external pure returns (uint160[])
+
+            function lenI8Vec() external view returns (uint256)
+            function pushI8Vec(int8 value) external
+            function popI8Vec() external returns (int8)
+            function getI8Vec(uint256 index) external view returns (int8)
+            function sliceI8Vec(uint256 offset, uint256 len) external view returns (int8[])
+
+            function lenU160Vec() external view returns (uint256)
+            function pushU160Vec(uint160 value) external
+            function popU160Vec() external returns (uint160)
+            function getU160Vec(uint256 index) external view returns (uint160)
+            function sliceU160Vec(uint256 offset, uint256 len) external view returns (uint160[])
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // Arrays as arguments and return values
+    let signed8 = vec![i8::MIN, -1, 0, 1, i8::MAX];
+    assert_eq!(
+        signed8,
+        counter.echo_i8_array(signed8.clone()).call().await?
+    );
+    let unsigned24 = [0, 1, 0x80_0000, 0xff_ffff];
+    assert_eq!(
+        unsigned24,
+        counter.echo_u24_fixed_array(unsigned24).call().await?
+    );
+    let nested = vec![vec![], vec![I256::MIN], vec![I256::minus_one(), I256::MAX]];
+    assert_eq!(
+        nested,
+        counter
+            .echo_i256_nested_array(nested.clone())
+            .call()
+            .await?
+    );
+    let unsigned160 = vec![U256::zero(), (U256::one() << 160) - 1];
+    assert_eq!(
+        unsigned160,
+        counter.echo_u160_array(unsigned160.clone()).call().await?
+    );
+    println!("Successfully echoed every array type");
+
+    // int8[] in storage, packed 32 elements to a slot
+    let start = counter.len_i8_vec().call().await?;
+    for value in &signed8 {
+        counter.push_i8_vec(*value).send().await?.await?;
+    }
+    assert_eq!(start + 5, counter.len_i8_vec().call().await?);
+    assert_eq!(i8::MIN, counter.get_i8_vec(start).call().await?);
+    assert_eq!(
+        signed8[1..4].to_vec(),
+        counter.slice_i8_vec(start + 1, 3.into()).call().await?
+    );
+    assert!(counter.get_i8_vec(start + 5).call().await.is_err());
+    assert!(counter.slice_i8_vec(start, 6.into()).call().await.is_err());
+    assert!(counter
+        .slice_i8_vec(U256::MAX, 2.into())
+        .call()
+        .await
+        .is_err());
+    assert_eq!(i8::MAX, counter.pop_i8_vec().call().await?);
+    counter.pop_i8_vec().send().await?.await?;
+    assert_eq!(start + 4, counter.len_i8_vec().call().await?);
+    println!("Successfully pushed, sliced and popped int8[]");
+
+    // uint160[] in storage, one element per slot
+    let start = counter.len_u160_vec().call().await?;
+    for value in &unsigned160 {
+        counter.push_u160_vec(*value).send().await?.await?;
+    }
+    assert_eq!(
+        unsigned160,
+        counter.slice_u160_vec(start, 2.into()).call().await?
+    );
+    assert_eq!(
+        unsigned160[1],
+        counter.get_u160_vec(start + 1).call().await?
+    );
+    while !counter.len_u160_vec().call().await?.is_zero() {
+        counter.pop_u160_vec().send().await?.await?;
+    }
+    assert!(counter.pop_u160_vec().call().await.is_err());
+    println!("Successfully pushed, sliced and emptied uint160[]");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,121 @@
+//! Calldata cases shared by the `dirty_calldata` and `dirty_calldata_decoding` examples.
+
+/// Calls with a single 32-byte argument word, and whether the program must accept them.
+pub const CASES: &[(&str, &str, bool)] = &[
+    // int8: the value must be sign extended from bit 7
+    (
+        "echoI8(int8)",
+        "000000000000000000000000000000000000000000000000000000000000007f",
+        true,
+    ),
+    (
+        "echoI8(int8)",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80",
+        true,
+    ),
+    (
+        "echoI8(int8)",
+        "0000000000000000000000000000000000000000000000000000000000000080",
+        false,
+    ),
+    (
+        "echoI8(int8)",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        false,
+    ),
+    // int24: the value must be sign extended from bit 23
+    (
+        "echoI24(int24)",
+        "00000000000000000000000000000000000000000000000000000000007fffff",
+        true,
+    ),
+    (
+        "echoI24(int24)",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff800000",
+        true,
+    ),
+    (
+        "echoI24(int24)",
+        "0000000000000000000000000000000000000000000000000000000000800000",
+        false,
+    ),
+    (
+        "echoI24(int24)",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7fffff",
+        false,
+    ),
+    (
+        "echoI24(int24)",
+        "0100000000000000000000000000000000000000000000000000000000000001",
+        false,
+    ),
+    (
+        "setI24(int24)",
+        "0000000000000000000000000000000000000000000000000000000000800000",
+        false,
+    ),
+    (
+        "setI24(int24)",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7fffff",
+        false,
+    ),
+    // int200: the value must be sign extended from bit 199
+    (
+        "echoI200(int200)",
+        "0000000000000080000000000000000000000000000000000000000000000000",
+        false,
+    ),
+    (
+        "echoI200(int200)",
+        "ffffffffffffff80000000000000000000000000000000000000000000000000",
+        true,
+    ),
+    // uint8: the upper 31 bytes must be zero
+    (
+        "echoU8(uint8)",
+        "00000000000000000000000000000000000000000000000000000000000000ff",
+        true,
+    ),
+    (
+        "echoU8(uint8)",
+        "0000000000000000000000000000000000000000000000000000000000000100",
+        false,
+    ),
+    (
+        "echoU8(uint8)",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        false,
+    ),
+    // uint24: the upper 29 bytes must be zero
+    (
+        "echoU24(uint24)",
+        "0000000000000000000000000000000000000000000000000000000001000000",
+        false,
+    ),
+    // uint160: the upper 12 bytes must be zero
+    (
+        "echoU160(uint160)",
+        "000000000000000000000000ffffffffffffffffffffffffffffffffffffffff",
+        true,
+    ),
+    (
+        "echoU160(uint160)",
+        "000000000000000000000001ffffffffffffffffffffffffffffffffffffffff",
+        false,
+    ),
+    (
+        "echoU160(uint160)",
+        "ff00000000000000000000000000000000000000000000000000000000000001",
+        false,
+    ),
+    (
+        "setU160(uint160)",
+        "000000000000000000000001ffffffffffffffffffffffffffffffffffffffff",
+        false,
+    ),
+    (
+        "setU160(uint160)",
+        "ff00000000000000000000000000000000000000000000000000000000000001",
+        false,
+    ),
+];
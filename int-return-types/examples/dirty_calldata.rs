@@ -0,0 +1,66 @@
+//! Example on how a deployed `int-return-types` program decodes calldata with dirty high bits.
+//! This example uses ethers-rs to send hand-crafted calldata to the program, where the 32-byte
+//! argument word carries a wrong sign extension or non-zero bytes above the type's width.
+//! Solidity rejects such calldata instead of truncating it, so every dirty case must revert.
+//! The canonical cases must round trip. The calls are `eth_call`s only, so no private key is
+//! needed, and they can be pointed at a local dev node to run without a testnet. The
+//! `dirty_calldata_decoding` example checks the same cases offline.
+
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::{Address, Bytes, TransactionRequest},
+    utils::{hex, id},
+};
+use eyre::eyre;
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[path = "cases/dirty_calldata.rs"]
+mod cases;
+
+use cases::CASES;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    for (signature, word, canonical) in CASES {
+        let mut calldata = id(signature).to_vec();
+        calldata.extend(hex::decode(word)?);
+        let tx = TransactionRequest::new()
+            .to(address)
+            .data(Bytes::from(calldata));
+        let result = provider.call(&tx.into(), None).await;
+
+        match (canonical, result) {
+            (true, Ok(output)) => {
+                // `set` calls return nothing, `echo` calls return the argument unchanged.
+                if signature.starts_with("echo") {
+                    assert_eq!(word, &hex::encode(&output), "{signature} {word}");
+                }
+                println!("{signature} accepted canonical {word}");
+            }
+            (false, Err(err)) => println!("{signature} rejected dirty {word}: {err}"),
+            (true, Err(err)) => {
+                return Err(eyre!("{signature} rejected canonical {word}: {err}"));
+            }
+            (false, Ok(output)) => {
+                return Err(eyre!(
+                    "{signature} accepted dirty {word} and returned {output}"
+                ));
+            }
+        }
+    }
+
+    println!("All {} cases match Solidity", CASES.len());
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! Example on how the router of `int-return-types` decodes calldata with dirty high bits.
+//! This example runs offline: it feeds the argument words of the `dirty_calldata` cases to the
+//! same `abi_decode_params(.., true)` the generated router calls on each method's argument types.
+//! Every dirty case must fail to decode, and every canonical case must decode and encode back to
+//! the same word, as the `echo` methods return it.
+
+#[path = "cases/dirty_calldata.rs"]
+mod cases;
+
+use alloy_sol_types::SolType;
+use cases::CASES;
+use eyre::eyre;
+use stylus_sdk::{
+    abi::AbiType,
+    alloy_primitives::{I200, I24, I8, U160, U24, U8},
+};
+
+/// Decodes the arguments as the router does for a method taking `$ty`, and encodes the result
+/// back.
+macro_rules! round_trip {
+    ($ty:ty, $args:expr) => {{
+        type Params = <$ty as AbiType>::SolType;
+        Params::abi_decode_params($args, true)
+            .ok()
+            .map(|decoded| Params::abi_encode_params(&decoded))
+    }};
+}
+
+fn main() -> eyre::Result<()> {
+    for (signature, word, canonical) in CASES {
+        let args = hex::decode(word)?;
+        let decoded = match *signature {
+            "echoI8(int8)" => round_trip!((I8,), &args),
+            "echoI24(int24)" | "setI24(int24)" => round_trip!((I24,), &args),
+            "echoI200(int200)" => round_trip!((I200,), &args),
+            "echoU8(uint8)" => round_trip!((U8,), &args),
+            "echoU24(uint24)" => round_trip!((U24,), &args),
+            "echoU160(uint160)" | "setU160(uint160)" => round_trip!((U160,), &args),
+            _ => return Err(eyre!("no argument types for {signature}")),
+        };
+
+        match (canonical, decoded) {
+            (true, Some(encoded)) => {
+                assert_eq!(args, encoded, "{signature} {word}");
+                println!("{signature} decodes canonical {word}");
+            }
+            (false, None) => println!("{signature} rejects dirty {word}"),
+            (true, None) => return Err(eyre!("{signature} rejected canonical {word}")),
+            (false, Some(_)) => return Err(eyre!("{signature} accepted dirty {word}")),
+        }
+    }
+
+    println!("All {} cases match Solidity", CASES.len());
+    Ok(())
+}
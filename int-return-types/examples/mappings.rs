@@ -0,0 +1,156 @@
+//! Example on how a deployed `int-return-types` program hashes integer mapping keys.
+//! This example uses ethers-rs to write mapping entries with negative and boundary keys, then
+//! reads the raw storage words with `rawSlot` at `keccak256(abi.encode(key, slot))`, which is
+//! where Solidity stores them. Signed keys must be sign extended to 32 bytes before hashing, so
+//! each negative key is also checked against the slot a zero-extended key would hash to.
+
+use ethers::{
+    abi::{encode, Token},
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, H256, I256, U256},
+    utils::keccak256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function getI8Map(int8 key) external view returns (uint256)
+            function setI8Map(int8 key, uint256 value) external
+            function getI24Map(int24 key) external view returns (uint24)
+            function setI24Map(int24 key, uint24 value) external
+            function getI200Map(int200 key) external view returns (int8)
+            function setI200Map(int200 key, int8 value) external
+            function getI256Map(int256 key) external view returns (bool)
+            function setI256Map(int256 key, bool value) external
+            function getU24Map(uint24 key) external view returns (int200)
+            function setU24Map(uint24 key, int200 value) external
+            function rawSlot(uint256 slot) external view returns (bytes32)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+    let layout = stylus_hello_world::Counter::layout();
+    let root = |label: &str| -> U256 {
+        let entry = layout.entries.iter().find(|e| e.label == label).unwrap();
+        U256::from(entry.slot)
+    };
+
+    // mapping(int8 => uint256)
+    counter.set_i8_map(-1, 42.into()).send().await?.await?;
+    counter.set_i8_map(i8::MAX, 7.into()).send().await?.await?;
+    assert_eq!(U256::from(42), counter.get_i8_map(-1).call().await?);
+    assert_eq!(U256::from(7), counter.get_i8_map(i8::MAX).call().await?);
+    let slot = signed_key_slot(I256::from(-1), root("signed8_map"));
+    assert_eq!(word(42), counter.raw_slot(slot).call().await?);
+    let slot = signed_key_slot(I256::from(i8::MAX), root("signed8_map"));
+    assert_eq!(word(7), counter.raw_slot(slot).call().await?);
+    let slot = unsigned_key_slot(U256::from(0xff), root("signed8_map"));
+    assert_eq!([0u8; 32], counter.raw_slot(slot).call().await?);
+    println!("mapping(int8 => uint256) keys hash like Solidity");
+
+    // mapping(int24 => uint24)
+    counter.set_i24_map(-8_388_608, 3).send().await?.await?;
+    assert_eq!(3, counter.get_i24_map(-8_388_608).call().await?);
+    let slot = signed_key_slot(I256::from(-8_388_608), root("signed24_map"));
+    assert_eq!(word(3), counter.raw_slot(slot).call().await?);
+    let slot = unsigned_key_slot(U256::from(0x80_0000), root("signed24_map"));
+    assert_eq!([0u8; 32], counter.raw_slot(slot).call().await?);
+    println!("mapping(int24 => uint24) keys hash like Solidity");
+
+    // mapping(int200 => int8), where the value is negative too
+    counter
+        .set_i200_map(I256::minus_one(), -1)
+        .send()
+        .await?
+        .await?;
+    assert_eq!(-1, counter.get_i200_map(I256::minus_one()).call().await?);
+    let slot = signed_key_slot(I256::minus_one(), root("signed200_map"));
+    let mut expected = [0u8; 32];
+    expected[31] = 0xff;
+    assert_eq!(expected, counter.raw_slot(slot).call().await?);
+    println!("mapping(int200 => int8) keys hash like Solidity");
+
+    // mapping(int256 => bool)
+    counter.set_i256_map(I256::MIN, true).send().await?.await?;
+    assert!(counter.get_i256_map(I256::MIN).call().await?);
+    assert!(!counter.get_i256_map(I256::MAX).call().await?);
+    let slot = signed_key_slot(I256::MIN, root("signed256_map"));
+    assert_eq!(word(1), counter.raw_slot(slot).call().await?);
+    println!("mapping(int256 => bool) keys hash like Solidity");
+
+    // mapping(uint24 => int200)
+    counter
+        .set_u24_map(0xff_ffff, I256::from(-5))
+        .send()
+        .await?
+        .await?;
+    assert_eq!(I256::from(-5), counter.get_u24_map(0xff_ffff).call().await?);
+    let slot = unsigned_key_slot(U256::from(0xff_ffff), root("unsigned24_map"));
+    let mut expected = [0xffu8; 32];
+    expected[..7].fill(0);
+    expected[31] = 0xfb;
+    assert_eq!(expected, counter.raw_slot(slot).call().await?);
+    println!("mapping(uint24 => int200) keys hash like Solidity");
+
+    Ok(())
+}
+
+/// The slot of `mapping[key]` for a signed key: `keccak256(abi.encode(key, slot))`, where
+/// `abi.encode` sign extends the key to 32 bytes.
+fn signed_key_slot(key: I256, root: U256) -> U256 {
+    let data = encode(&[Token::Int(key.into_raw()), Token::Uint(root)]);
+    U256::from(keccak256(data))
+}
+
+/// The slot of `mapping[key]` for an unsigned key.
+fn unsigned_key_slot(key: U256, root: U256) -> U256 {
+    let data = encode(&[Token::Uint(key), Token::Uint(root)]);
+    U256::from(keccak256(data))
+}
+
+/// A storage word holding a small unsigned value.
+fn word(value: u64) -> [u8; 32] {
+    H256::from_low_u64_be(value).0
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
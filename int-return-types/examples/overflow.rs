@@ -0,0 +1,171 @@
+//! Example on how the overflow-aware entrypoints of a deployed `int-return-types` program behave.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It sets `int8` and `uint8` to their limits and checks that the plain and checked variants
+//! revert with `Panic(0x11)`, the wrapping variants wrap around, and the saturating variants stop
+//! at the bound, matching Solidity 0.8 and `unchecked {}` blocks.
+
+use ethers::{
+    abi::AbiDecode,
+    contract::ContractError,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes, U256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Selector of Solidity's `Panic(uint256)` error.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Panic code for an arithmetic underflow or overflow.
+const PANIC_ARITHMETIC_OVERFLOW: u64 = 0x11;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function getI8() external view returns (int8)
+            function setI8(int8 value) external
+            function incrementI8() external
+            function decrementI8() external
+            function incrementCheckedI8() external
+            function incrementWrappingI8() external
+            function incrementSaturatingI8() external
+            function decrementCheckedI8() external
+            function decrementWrappingI8() external
+            function decrementSaturatingI8() external
+
+            function getU8() external view returns (uint8)
+            function setU8(uint8 value) external
+            function incrementU8() external
+            function decrementU8() external
+            function incrementCheckedU8() external
+            function incrementWrappingU8() external
+            function incrementSaturatingU8() external
+            function decrementCheckedU8() external
+            function decrementWrappingU8() external
+            function decrementSaturatingU8() external
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // int8 at its maximum
+    counter.set_i8(i8::MAX).send().await?.await?;
+    let result = counter.increment_i8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    let result = counter.increment_checked_i8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    println!("incrementI8 and incrementCheckedI8 reverted at {}", i8::MAX);
+
+    counter.increment_saturating_i8().send().await?.await?;
+    assert_eq!(i8::MAX, counter.get_i8().call().await?);
+    println!("incrementSaturatingI8 stayed at {}", i8::MAX);
+
+    counter.increment_wrapping_i8().send().await?.await?;
+    assert_eq!(i8::MIN, counter.get_i8().call().await?);
+    println!("incrementWrappingI8 wrapped to {}", i8::MIN);
+
+    // int8 at its minimum
+    let result = counter.decrement_i8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    let result = counter.decrement_checked_i8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    println!("decrementI8 and decrementCheckedI8 reverted at {}", i8::MIN);
+
+    counter.decrement_saturating_i8().send().await?.await?;
+    assert_eq!(i8::MIN, counter.get_i8().call().await?);
+    println!("decrementSaturatingI8 stayed at {}", i8::MIN);
+
+    counter.decrement_wrapping_i8().send().await?.await?;
+    assert_eq!(i8::MAX, counter.get_i8().call().await?);
+    println!("decrementWrappingI8 wrapped to {}", i8::MAX);
+
+    // uint8 at its maximum
+    counter.set_u8(u8::MAX).send().await?.await?;
+    let result = counter.increment_u8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    let result = counter.increment_checked_u8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    println!("incrementU8 and incrementCheckedU8 reverted at {}", u8::MAX);
+
+    counter.increment_saturating_u8().send().await?.await?;
+    assert_eq!(u8::MAX, counter.get_u8().call().await?);
+    println!("incrementSaturatingU8 stayed at {}", u8::MAX);
+
+    counter.increment_wrapping_u8().send().await?.await?;
+    assert_eq!(0, counter.get_u8().call().await?);
+    println!("incrementWrappingU8 wrapped to 0");
+
+    // uint8 at its minimum
+    let result = counter.decrement_u8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    let result = counter.decrement_checked_u8().call().await;
+    assert_panic(result, PANIC_ARITHMETIC_OVERFLOW)?;
+    println!("decrementU8 and decrementCheckedU8 reverted at 0");
+
+    counter.decrement_saturating_u8().send().await?.await?;
+    assert_eq!(0, counter.get_u8().call().await?);
+    println!("decrementSaturatingU8 stayed at 0");
+
+    counter.decrement_wrapping_u8().send().await?.await?;
+    assert_eq!(u8::MAX, counter.get_u8().call().await?);
+    println!("decrementWrappingU8 wrapped to {}", u8::MAX);
+
+    Ok(())
+}
+
+/// Asserts that a call reverted with Solidity's `Panic(uint256)` error and the given code.
+fn assert_panic<T, M: Middleware>(
+    result: Result<T, ContractError<M>>,
+    code: u64,
+) -> eyre::Result<()> {
+    let data: Bytes = match result {
+        Err(err) => err
+            .as_revert()
+            .cloned()
+            .ok_or_else(|| eyre!("call failed without revert data: {err}"))?,
+        Ok(_) => return Err(eyre!("call succeeded, expected Panic({code:#x})")),
+    };
+    assert_eq!(PANIC_SELECTOR, data[..4]);
+    assert_eq!(U256::from(code), U256::decode(&data[4..])?);
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,126 @@
+//! Example on how to check the storage packing of a deployed `int-return-types` program.
+//! This example uses ethers-rs to set every integer field to a distinct value, then reads the
+//! raw storage words back with `rawSlot` and checks each value sits at the slot and offset
+//! given by the generated storage layout, which matches Solidity's packing.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, I256, U256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+use stylus_hello_world::layout;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function setI8(int8 value) external
+            function setI16(int16 value) external
+            function setI24(int24 value) external
+            function setI32(int32 value) external
+            function setI64(int64 value) external
+            function setI128(int128 value) external
+            function setI160(int160 value) external
+            function setI200(int200 value) external
+            function setI256(int256 value) external
+            function setU8(uint8 value) external
+            function setU16(uint16 value) external
+            function setU24(uint24 value) external
+            function setU32(uint32 value) external
+            function setU64(uint64 value) external
+            function setU128(uint128 value) external
+            function setU160(uint160 value) external
+            function setU200(uint200 value) external
+            function setU256(uint256 value) external
+            function rawSlot(uint256 slot) external view returns (bytes32)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // Negative values check that sign extension does not spill into neighbouring fields.
+    counter.set_i8(-2).send().await?.await?;
+    counter.set_i16(-3).send().await?.await?;
+    counter.set_i24((-4).into()).send().await?.await?;
+    counter.set_i32(-5).send().await?.await?;
+    counter.set_i64(-6).send().await?.await?;
+    counter.set_i128(-7).send().await?.await?;
+    counter.set_i160((-8).into()).send().await?.await?;
+    counter.set_i200((-9).into()).send().await?.await?;
+    counter.set_i256((-10).into()).send().await?.await?;
+    counter.set_u8(11).send().await?.await?;
+    counter.set_u16(12).send().await?.await?;
+    counter.set_u24(13u32.into()).send().await?.await?;
+    counter.set_u32(14).send().await?.await?;
+    counter.set_u64(15).send().await?.await?;
+    counter.set_u128(16).send().await?.await?;
+    counter.set_u160(17.into()).send().await?.await?;
+    counter.set_u200(18.into()).send().await?.await?;
+    counter.set_u256(19.into()).send().await?.await?;
+    println!("Successfully set every field via a tx");
+
+    let expected: [i64; 18] = [
+        -2, -3, -4, -5, -6, -7, -8, -9, -10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+    ];
+    let layout = stylus_hello_world::Counter::layout();
+    for (entry, value) in layout.entries.iter().zip(expected) {
+        let word = counter.raw_slot(entry.slot.into()).call().await?;
+        let size = layout::type_size(&entry.ty);
+        // Offsets count from the lowest-order byte of the big-endian word.
+        let end = 32 - entry.offset;
+        let bytes = &word[end - size..end];
+
+        // Sign extend the field to 256 bits and compare it with the value that was set.
+        let fill = if value < 0 { 0xff } else { 0x00 };
+        let mut extended = [fill; 32];
+        extended[32 - size..].copy_from_slice(bytes);
+        let actual = I256::from_raw(U256::from_big_endian(&extended));
+        assert_eq!(I256::from(value), actual, "{}", entry.label);
+        println!(
+            "{} = {} at slot {} offset {}",
+            entry.label, actual, entry.slot, entry.offset
+        );
+    }
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,67 @@
+//! Example on how to check the storage layout of `int-return-types` against Solidity.
+//! This example compiles the Solidity reference in `layout/Counter.sol` with
+//! `solc --storage-layout` and compares every state variable's slot, offset and type with the
+//! layout generated from the `sol_storage!` declaration. It runs offline, but needs `solc` on
+//! the `PATH` (or set `SOLC` to its path):
+//!
+//! ```
+//! cargo run --example storage_layout --features export-abi --target=<YOUR_ARCHITECTURE>
+//! ```
+
+use eyre::eyre;
+use serde_json::Value;
+use std::process::Command;
+use stylus_hello_world::Counter;
+
+/// Solidity reference for `Counter`.
+const REFERENCE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/layout/Counter.sol");
+
+fn main() -> eyre::Result<()> {
+    let solc = std::env::var("SOLC").unwrap_or_else(|_| "solc".to_string());
+    let output = Command::new(&solc)
+        .args(["--combined-json", "storage-layout", REFERENCE])
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(
+            "{solc} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let combined: Value = serde_json::from_slice(&output.stdout)?;
+    let contract = combined["contracts"]
+        .as_object()
+        .and_then(|contracts| {
+            contracts
+                .iter()
+                .find(|(name, _)| name.ends_with(":Counter"))
+        })
+        .map(|(_, contract)| &contract["storage-layout"])
+        .ok_or_else(|| eyre!("no Counter in solc output"))?;
+    // Older versions of solc nest the layout as a JSON string.
+    let expected: Value = match contract {
+        Value::String(layout) => serde_json::from_str(layout)?,
+        layout => layout.clone(),
+    };
+    let actual: Value = serde_json::from_str(&Counter::layout().to_json())?;
+
+    let expected_storage = expected["storage"].as_array().unwrap();
+    let actual_storage = actual["storage"].as_array().unwrap();
+    assert_eq!(expected_storage.len(), actual_storage.len());
+    for (expected, actual) in expected_storage.iter().zip(actual_storage) {
+        for key in ["label", "offset", "slot", "type"] {
+            assert_eq!(expected[key], actual[key], "{} {key}", expected["label"]);
+        }
+        println!(
+            "{} at slot {} offset {} matches",
+            actual["label"], actual["slot"], actual["offset"]
+        );
+    }
+    for (id, ty) in actual["types"].as_object().unwrap() {
+        for key in ["encoding", "label", "numberOfBytes", "key", "value", "base"] {
+            assert_eq!(expected["types"][id][key], ty[key], "{id} {key}");
+        }
+    }
+
+    println!("Storage layout of Counter matches {REFERENCE}");
+    Ok(())
+}
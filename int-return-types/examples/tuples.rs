@@ -0,0 +1,80 @@
+//! Example on how multiple return values of a deployed `int-return-types` program are decoded.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It decodes the `(uint256, int8, address, bool)` tuple `getMixed` returns.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function setI8(int8 value) external
+            function setU256(uint256 value) external
+            function getMixed() external view returns (uint256, int8, address, bool)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    counter.set_i8(-5).send().await?.await?;
+    counter.set_u256(U256::MAX).send().await?.await?;
+    println!("Successfully set I8 and U256 counters via a tx");
+
+    let (unsigned256, signed8, sender, negative) =
+        counter.get_mixed().from(wallet.address()).call().await?;
+    println!(
+        "Counter values = {:?}",
+        (unsigned256, signed8, sender, negative)
+    );
+    assert_eq!(U256::MAX, unsigned256);
+    assert_eq!(-5, signed8);
+    assert_eq!(wallet.address(), sender);
+    assert!(negative);
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.23;
+
+// Solidity reference for the storage of `Counter` in `src/lib.rs`.
+// Fields must stay in the same order and with the same types as the `sol_storage!` declaration.
+contract Counter {
+    int8 signed8;
+    int16 signed16;
+    int24 signed24;
+    int32 signed32;
+    int64 signed64;
+    int128 signed128;
+    int160 signed160;
+    int200 signed200;
+    int256 signed256;
+    uint8 unsigned8;
+    uint16 unsigned16;
+    uint24 unsigned24;
+    uint32 unsigned32;
+    uint64 unsigned64;
+    uint128 unsigned128;
+    uint160 unsigned160;
+    uint200 unsigned200;
+    uint256 unsigned256;
+    mapping(int8 => uint256) signed8_map;
+    mapping(int16 => int16) signed16_map;
+    mapping(int24 => uint24) signed24_map;
+    mapping(int32 => bool) signed32_map;
+    mapping(int64 => int64) signed64_map;
+    mapping(int128 => uint128) signed128_map;
+    mapping(int160 => uint160) signed160_map;
+    mapping(int200 => int8) signed200_map;
+    mapping(int256 => bool) signed256_map;
+    mapping(uint8 => int256) unsigned8_map;
+    mapping(uint16 => uint16) unsigned16_map;
+    mapping(uint24 => int200) unsigned24_map;
+    mapping(uint32 => bool) unsigned32_map;
+    mapping(uint64 => uint64) unsigned64_map;
+    mapping(uint128 => int128) unsigned128_map;
+    mapping(uint160 => uint8) unsigned160_map;
+    mapping(uint200 => uint256) unsigned200_map;
+    mapping(uint256 => int24) unsigned256_map;
+    int8[] signed8_vec;
+    uint24[] unsigned24_vec;
+    uint160[] unsigned160_vec;
+    int256[] signed256_vec;
+}
@@ -0,0 +1,146 @@
+//! Solidity 0.8 integer semantics for every width.
+//!
+//! Operations revert the way Solidity's checked arithmetic does: `Panic(0x11)` on overflow
+//! and `Panic(0x12)` on division or modulo by zero. Shift amounts are `uint256`, like the
+//! EVM's `SHL`, `SHR` and `SAR` opcodes, and shifting by the width or more is not an error.
+
+use alloc::vec::Vec;
+use alloy_primitives::{Signed, Uint, U256};
+use alloy_sol_types::{Panic, PanicKind, SolError};
+
+/// Revert data for an arithmetic underflow or overflow, encoded the way
+/// Solidity 0.8 does it outside of `unchecked {}` blocks: `Panic(0x11)`.
+pub fn arithmetic_overflow() -> Vec<u8> {
+    Panic::from(PanicKind::UnderOverflow).abi_encode()
+}
+
+/// Revert data for a division or modulo by zero: `Panic(0x12)`.
+pub fn division_by_zero() -> Vec<u8> {
+    Panic::from(PanicKind::DivisionByZero).abi_encode()
+}
+
+/// Converts a `uint256` shift amount into a bit count, or `None` if every bit is shifted out.
+fn shift_amount(shift: U256, bits: usize) -> Option<usize> {
+    (shift < U256::from(bits)).then(|| shift.to::<usize>())
+}
+
+/// Signed operations with Solidity semantics.
+pub mod signed {
+    use super::*;
+
+    /// Division rounding toward zero. `MIN / -1` overflows, as in Solidity.
+    pub fn div<const BITS: usize, const LIMBS: usize>(
+        a: Signed<BITS, LIMBS>,
+        b: Signed<BITS, LIMBS>,
+    ) -> Result<Signed<BITS, LIMBS>, Vec<u8>> {
+        if b.is_zero() {
+            return Err(division_by_zero());
+        }
+        a.checked_div(b).ok_or_else(arithmetic_overflow)
+    }
+
+    /// Remainder taking the sign of the dividend. `MIN % -1` is zero, as in Solidity.
+    pub fn rem<const BITS: usize, const LIMBS: usize>(
+        a: Signed<BITS, LIMBS>,
+        b: Signed<BITS, LIMBS>,
+    ) -> Result<Signed<BITS, LIMBS>, Vec<u8>> {
+        if b.is_zero() {
+            return Err(division_by_zero());
+        }
+        Ok(a.wrapping_rem(b))
+    }
+
+    /// Checked exponentiation with an unsigned exponent of the same width.
+    pub fn exp<const BITS: usize, const LIMBS: usize>(
+        base: Signed<BITS, LIMBS>,
+        exponent: Uint<BITS, LIMBS>,
+    ) -> Result<Signed<BITS, LIMBS>, Vec<u8>> {
+        base.checked_pow(exponent).ok_or_else(arithmetic_overflow)
+    }
+
+    /// Left shift of the two's complement representation, discarding overflowing bits.
+    pub fn shl<const BITS: usize, const LIMBS: usize>(
+        a: Signed<BITS, LIMBS>,
+        shift: U256,
+    ) -> Signed<BITS, LIMBS> {
+        match shift_amount(shift, BITS) {
+            Some(shift) => Signed::from_raw(a.into_raw() << shift),
+            None => Signed::ZERO,
+        }
+    }
+
+    /// Logical right shift of the two's complement representation: the EVM's `SHR`, or
+    /// `intN(uintN(a) >> shift)` in Solidity, whose `>>` is arithmetic on signed types.
+    pub fn shr<const BITS: usize, const LIMBS: usize>(
+        a: Signed<BITS, LIMBS>,
+        shift: U256,
+    ) -> Signed<BITS, LIMBS> {
+        match shift_amount(shift, BITS) {
+            Some(shift) => Signed::from_raw(a.into_raw() >> shift),
+            None => Signed::ZERO,
+        }
+    }
+
+    /// Arithmetic right shift rounding toward negative infinity (Solidity's `>>` on signed types).
+    pub fn sar<const BITS: usize, const LIMBS: usize>(
+        a: Signed<BITS, LIMBS>,
+        shift: U256,
+    ) -> Signed<BITS, LIMBS> {
+        match shift_amount(shift, BITS) {
+            Some(shift) => a.asr(shift),
+            None if a.is_negative() => Signed::MINUS_ONE,
+            None => Signed::ZERO,
+        }
+    }
+}
+
+/// Unsigned operations with Solidity semantics.
+pub mod unsigned {
+    use super::*;
+
+    /// Division rounding toward zero.
+    pub fn div<const BITS: usize, const LIMBS: usize>(
+        a: Uint<BITS, LIMBS>,
+        b: Uint<BITS, LIMBS>,
+    ) -> Result<Uint<BITS, LIMBS>, Vec<u8>> {
+        a.checked_div(b).ok_or_else(division_by_zero)
+    }
+
+    /// Remainder of the division.
+    pub fn rem<const BITS: usize, const LIMBS: usize>(
+        a: Uint<BITS, LIMBS>,
+        b: Uint<BITS, LIMBS>,
+    ) -> Result<Uint<BITS, LIMBS>, Vec<u8>> {
+        a.checked_rem(b).ok_or_else(division_by_zero)
+    }
+
+    /// Checked exponentiation.
+    pub fn exp<const BITS: usize, const LIMBS: usize>(
+        base: Uint<BITS, LIMBS>,
+        exponent: Uint<BITS, LIMBS>,
+    ) -> Result<Uint<BITS, LIMBS>, Vec<u8>> {
+        base.checked_pow(exponent).ok_or_else(arithmetic_overflow)
+    }
+
+    /// Left shift, discarding overflowing bits.
+    pub fn shl<const BITS: usize, const LIMBS: usize>(
+        a: Uint<BITS, LIMBS>,
+        shift: U256,
+    ) -> Uint<BITS, LIMBS> {
+        match shift_amount(shift, BITS) {
+            Some(shift) => a << shift,
+            None => Uint::ZERO,
+        }
+    }
+
+    /// Logical right shift.
+    pub fn shr<const BITS: usize, const LIMBS: usize>(
+        a: Uint<BITS, LIMBS>,
+        shift: U256,
+    ) -> Uint<BITS, LIMBS> {
+        match shift_amount(shift, BITS) {
+            Some(shift) => a >> shift,
+            None => Uint::ZERO,
+        }
+    }
+}
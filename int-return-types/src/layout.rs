@@ -0,0 +1,154 @@
+//! Storage layout of the `sol_storage!` structs in this crate.
+//!
+//! Each struct declared with `sol_storage_with_layout!` gets a `layout()` parsed from the same
+//! tokens `sol_storage!` expands, so the layout can't drift from the declaration. The fields
+//! are packed the way Solidity packs state variables, and [`StorageLayout::to_json`]
+//! produces the same shape as `solc --storage-layout`, so the layout can be diffed against the
+//! equivalent Solidity contract before an upgrade or when sharing storage with a proxy.
+
+use std::collections::BTreeMap;
+
+/// A state variable placed in storage.
+pub struct StorageEntry {
+    pub label: &'static str,
+    pub ty: String,
+    pub slot: usize,
+    pub offset: usize,
+}
+
+/// The storage layout of a single contract.
+pub struct StorageLayout {
+    pub contract: &'static str,
+    pub entries: Vec<StorageEntry>,
+}
+
+impl StorageLayout {
+    /// Places the fields of a stringified `sol_storage!` body, such as `int8 a; uint24[] b;`, in
+    /// declaration order. A field starts a new slot when it does not fit in the remaining bytes
+    /// of the current one.
+    pub fn parse(contract: &'static str, body: &'static str) -> Self {
+        let mut entries = Vec::new();
+        let (mut slot, mut offset) = (0, 0);
+        for field in body
+            .split(';')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+        {
+            let (ty, label) = field
+                .rsplit_once(char::is_whitespace)
+                .unwrap_or_else(|| panic!("unsupported storage field {field}"));
+            // `stringify!` spaces tokens freely, as in `int8 []`.
+            let ty = ty
+                .split_whitespace()
+                .collect::<String>()
+                .replace("=>", " => ");
+            let size = type_size(&ty);
+            if offset + size > 32 {
+                slot += 1;
+                offset = 0;
+            }
+            entries.push(StorageEntry {
+                label,
+                ty,
+                slot,
+                offset,
+            });
+            offset += size;
+        }
+        Self { contract, entries }
+    }
+
+    /// Renders the layout in the JSON shape of `solc --storage-layout`.
+    pub fn to_json(&self) -> String {
+        let mut storage = Vec::with_capacity(self.entries.len());
+        let mut types = BTreeMap::new();
+        for entry in &self.entries {
+            storage.push(format!(
+                r#"{{"astId":0,"contract":"{}","label":"{}","offset":{},"slot":"{}","type":"{}"}}"#,
+                self.contract,
+                entry.label,
+                entry.offset,
+                entry.slot,
+                type_id(&entry.ty),
+            ));
+            collect_types(&entry.ty, &mut types);
+        }
+        let types: Vec<String> = types
+            .into_iter()
+            .map(|(id, description)| format!(r#""{id}":{description}"#))
+            .collect();
+        format!(
+            r#"{{"storage":[{}],"types":{{{}}}}}"#,
+            storage.join(","),
+            types.join(",")
+        )
+    }
+}
+
+/// Splits `mapping(K => V)` into its key and value types.
+fn mapping_types(ty: &str) -> Option<(&str, &str)> {
+    ty.strip_prefix("mapping(")?
+        .strip_suffix(')')?
+        .split_once(" => ")
+}
+
+/// The element type of a dynamic array `T[]`.
+fn array_base(ty: &str) -> Option<&str> {
+    ty.strip_suffix("[]")
+}
+
+/// The `solc` identifier of a type, such as `t_uint256`, `t_mapping(t_int8,t_uint256)` or
+/// `t_array(t_int8)dyn_storage`.
+fn type_id(ty: &str) -> String {
+    if let Some((key, value)) = mapping_types(ty) {
+        return format!("t_mapping({},{})", type_id(key), type_id(value));
+    }
+    if let Some(base) = array_base(ty) {
+        return format!("t_array({})dyn_storage", type_id(base));
+    }
+    format!("t_{ty}")
+}
+
+/// Adds the `solc` description of a type, and of the types it refers to, to `types`.
+fn collect_types(ty: &str, types: &mut BTreeMap<String, String>) {
+    let description = if let Some((key, value)) = mapping_types(ty) {
+        collect_types(key, types);
+        collect_types(value, types);
+        format!(
+            r#"{{"encoding":"mapping","key":"{}","label":"{ty}","numberOfBytes":"32","value":"{}"}}"#,
+            type_id(key),
+            type_id(value),
+        )
+    } else if let Some(base) = array_base(ty) {
+        collect_types(base, types);
+        format!(
+            r#"{{"base":"{}","encoding":"dynamic_array","label":"{ty}","numberOfBytes":"32"}}"#,
+            type_id(base),
+        )
+    } else {
+        format!(
+            r#"{{"encoding":"inplace","label":"{ty}","numberOfBytes":"{}"}}"#,
+            type_size(ty)
+        )
+    };
+    types.insert(type_id(ty), description);
+}
+
+/// The number of bytes a type takes in the slot it is declared in.
+pub fn type_size(ty: &str) -> usize {
+    if mapping_types(ty).is_some() || array_base(ty).is_some() {
+        return 32;
+    }
+    match ty {
+        "bool" => 1,
+        "address" => 20,
+        _ => {
+            let bits = ty
+                .strip_prefix("uint")
+                .or_else(|| ty.strip_prefix("int"))
+                .and_then(|bits| bits.parse::<usize>().ok())
+                .unwrap_or_else(|| panic!("unsupported storage type {ty}"));
+            bits / 8
+        }
+    }
+}
@@ -1,4 +1,30 @@
 //! Stylus Integer Return Types Test Contract
+//!
+//! Every width has a plain `increment`/`decrement`, plus `Checked`, `Wrapping` and
+//! `Saturating` variants. The plain and checked variants revert with `Panic(0x11)` like
+//! Solidity 0.8 arithmetic, while the wrapping variants behave like an `unchecked {}` block.
+//!
+//! Every width also has pure entrypoints for the full Solidity operator set (`add`, `sub`,
+//! `mul`, `div`, `mod`, `exp`, shifts, bitwise operators and the six comparisons `lt`, `gt`,
+//! `le`, `ge`, `eq` and `ne`), so the contract can be used as an oracle for Stylus integer
+//! semantics. See [`arith`] for the rules.
+//!
+//! Widths below 256 bits have a pure `echo` entrypoint that returns its argument as decoded
+//! by the router, to check how calldata with dirty high bits is handled.
+//!
+//! `rawSlot` exposes the raw storage words, so the packing of `Counter` can be checked
+//! against the layout in [`layout`], which is printed in the `solc --storage-layout` JSON
+//! shape by `cargo run --features export-abi -- --storage-layout`.
+//!
+//! Every integer field can be read and written in a single call through the `AllInts` struct
+//! with `getAll` and `setAll`, and `getMixed` returns several widths as a tuple.
+//!
+//! Each width is also used as the key of a mapping, to check that keys are hashed into the
+//! same slots as Solidity's `keccak256(abi.encode(key, slot))`, including negative keys.
+//!
+//! Arrays of odd-width integers are covered both as arguments and return values (`int8[]`,
+//! `uint24[4]`, `int256[][]`, `uint160[]`) and as storage vectors with `push`, `pop`, `get`
+//! and `slice`, which revert with Solidity's `Panic(0x31)` and `Panic(0x32)` codes.
 
 // Allow `cargo stylus export-abi` to generate a main function.
 #![cfg_attr(not(feature = "export-abi"), no_main)]
@@ -8,10 +34,21 @@ extern crate alloc;
 #[global_allocator]
 static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
 
+mod arith;
+#[cfg(feature = "export-abi")]
+pub mod layout;
+
+use crate::arith::{arithmetic_overflow, signed, unsigned};
+use alloc::vec::Vec;
 use alloy_primitives::{Signed, Uint};
+use alloy_sol_types::{sol, Panic, PanicKind, SolError};
+use core::ops::Range;
 /// Import items from the SDK. The prelude contains common traits and macros.
 use stylus_sdk::{
-    alloy_primitives::{I128, I16, I160, I256, I32, I64, I8, U128, U16, U160, U256, U32, U64, U8},
+    alloy_primitives::{
+        Address, B256, I128, I16, I160, I256, I32, I64, I8, U128, U16, U160, U256, U32, U64, U8,
+    },
+    hostio, msg,
     prelude::*,
 };
 
@@ -20,9 +57,50 @@ type I200 = Signed<200, 4>;
 type U24 = Uint<24, 1>;
 type U200 = Uint<200, 4>;
 
+/// Revert data for `pop()` on an empty array: `Panic(0x31)`.
+fn empty_array_pop() -> Vec<u8> {
+    Panic::from(PanicKind::EmptyArrayPop).abi_encode()
+}
+
+/// Revert data for an out-of-bounds array access: `Panic(0x32)`.
+fn array_out_of_bounds() -> Vec<u8> {
+    Panic::from(PanicKind::ArrayOutOfBounds).abi_encode()
+}
+
+/// The indices of `len` elements starting at `offset`, if they all fit in an array of `size`.
+fn slice_range(offset: U256, len: U256, size: usize) -> Result<Range<usize>, Vec<u8>> {
+    let end = offset
+        .checked_add(len)
+        .filter(|end| *end <= U256::from(size))
+        .ok_or_else(array_out_of_bounds)?;
+    Ok(offset.to::<usize>()..end.to::<usize>())
+}
+
+/// Declares storage with `sol_storage!` and, under `export-abi`, a `layout()` parsed from the
+/// same fields, so every struct declared this way has a storage layout that can't drift.
+macro_rules! sol_storage_with_layout {
+    ($(#[$($attr:tt)*])* pub struct $name:ident { $($fields:tt)* }) => {
+        sol_storage! {
+            $(#[$($attr)*])*
+            pub struct $name { $($fields)* }
+        }
+
+        #[cfg(feature = "export-abi")]
+        impl $name {
+            /// The storage layout of the struct, in declaration order.
+            pub fn layout() -> layout::StorageLayout {
+                layout::StorageLayout::parse(
+                    concat!("src/lib.rs:", stringify!($name)),
+                    stringify!($($fields)*),
+                )
+            }
+        }
+    };
+}
+
 // Define some persistent storage using the Solidity ABI.
 // `Counter` will be the entrypoint.
-sol_storage! {
+sol_storage_with_layout! {
     #[entrypoint]
     pub struct Counter {
         int8 signed8;
@@ -43,6 +121,52 @@ sol_storage! {
         uint160 unsigned160;
         uint200 unsigned200;
         uint256 unsigned256;
+        mapping(int8 => uint256) signed8_map;
+        mapping(int16 => int16) signed16_map;
+        mapping(int24 => uint24) signed24_map;
+        mapping(int32 => bool) signed32_map;
+        mapping(int64 => int64) signed64_map;
+        mapping(int128 => uint128) signed128_map;
+        mapping(int160 => uint160) signed160_map;
+        mapping(int200 => int8) signed200_map;
+        mapping(int256 => bool) signed256_map;
+        mapping(uint8 => int256) unsigned8_map;
+        mapping(uint16 => uint16) unsigned16_map;
+        mapping(uint24 => int200) unsigned24_map;
+        mapping(uint32 => bool) unsigned32_map;
+        mapping(uint64 => uint64) unsigned64_map;
+        mapping(uint128 => int128) unsigned128_map;
+        mapping(uint160 => uint8) unsigned160_map;
+        mapping(uint200 => uint256) unsigned200_map;
+        mapping(uint256 => int24) unsigned256_map;
+        int8[] signed8_vec;
+        uint24[] unsigned24_vec;
+        uint160[] unsigned160_vec;
+        int256[] signed256_vec;
+    }
+}
+
+sol! {
+    #[derive(AbiType)]
+    struct AllInts {
+        int8 signed8;
+        int16 signed16;
+        int24 signed24;
+        int32 signed32;
+        int64 signed64;
+        int128 signed128;
+        int160 signed160;
+        int200 signed200;
+        int256 signed256;
+        uint8 unsigned8;
+        uint16 unsigned16;
+        uint24 unsigned24;
+        uint32 unsigned32;
+        uint64 unsigned64;
+        uint128 unsigned128;
+        uint160 unsigned160;
+        uint200 unsigned200;
+        uint256 unsigned256;
     }
 }
 
@@ -61,13 +185,138 @@ impl Counter {
         self.signed8.set(value)
     }
 
+    pub fn echo_i8(value: I8) -> I8 {
+        value
+    }
+
     pub fn set_i8_builtin(&mut self, value: i8) {
         self.signed8.set(value.try_into().unwrap())
     }
 
-    pub fn increment_i8(&mut self) {
+    pub fn increment_i8(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i8()
+    }
+
+    pub fn increment_checked_i8(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed8.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed8.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_i8(&mut self) {
+        let current = self.signed8.get();
+        self.signed8
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn increment_saturating_i8(&mut self) {
+        let current = self.signed8.get();
+        self.signed8
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i8(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i8()
+    }
+
+    pub fn decrement_checked_i8(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed8.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed8.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i8(&mut self) {
         let current = self.signed8.get();
-        self.signed8.set(current + Signed::try_from(1).unwrap());
+        self.signed8
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i8(&mut self) {
+        let current = self.signed8.get();
+        self.signed8
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i8(a: I8, b: I8) -> Result<I8, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i8(a: I8, b: I8) -> Result<I8, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i8(a: I8, b: I8) -> Result<I8, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i8(a: I8, b: I8) -> Result<I8, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i8(a: I8, b: I8) -> Result<I8, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i8(base: I8, exponent: U8) -> Result<I8, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i8(a: I8, shift: U256) -> I8 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i8(a: I8, shift: U256) -> I8 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i8(a: I8, shift: U256) -> I8 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i8(a: I8, b: I8) -> I8 {
+        a & b
+    }
+
+    pub fn or_i8(a: I8, b: I8) -> I8 {
+        a | b
+    }
+
+    pub fn xor_i8(a: I8, b: I8) -> I8 {
+        a ^ b
+    }
+
+    pub fn not_i8(a: I8) -> I8 {
+        !a
+    }
+
+    pub fn lt_i8(a: I8, b: I8) -> bool {
+        a < b
+    }
+
+    pub fn gt_i8(a: I8, b: I8) -> bool {
+        a > b
+    }
+
+    pub fn eq_i8(a: I8, b: I8) -> bool {
+        a == b
+    }
+
+    pub fn ne_i8(a: I8, b: I8) -> bool {
+        a != b
+    }
+
+    pub fn le_i8(a: I8, b: I8) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i8(a: I8, b: I8) -> bool {
+        a >= b
     }
 
     pub fn get_i16(&self) -> I16 {
@@ -82,284 +331,2647 @@ impl Counter {
         self.signed16.set(value)
     }
 
+    pub fn echo_i16(value: I16) -> I16 {
+        value
+    }
+
     pub fn set_i16_builtin(&mut self, value: i16) {
         self.signed16.set(value.try_into().unwrap())
     }
 
-    pub fn increment_i16(&mut self) {
+    pub fn increment_i16(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i16()
+    }
+
+    pub fn increment_checked_i16(&mut self) -> Result<(), Vec<u8>> {
         let current = self.signed16.get();
-        self.signed16.set(current + Signed::try_from(1).unwrap());
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed16.set(next);
+        Ok(())
     }
 
-    pub fn get_i24(&self) -> I24 {
-        self.signed24.get()
+    pub fn increment_wrapping_i16(&mut self) {
+        let current = self.signed16.get();
+        self.signed16
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
     }
 
-    pub fn set_i24(&mut self, value: I24) {
-        self.signed24.set(value)
+    pub fn increment_saturating_i16(&mut self) {
+        let current = self.signed16.get();
+        self.signed16
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
     }
 
-    pub fn increment_i24(&mut self) {
-        let current = self.signed24.get();
-        self.signed24.set(current + Signed::try_from(1).unwrap());
+    pub fn decrement_i16(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i16()
     }
 
-    pub fn get_i32(&self) -> I32 {
-        self.signed32.get()
+    pub fn decrement_checked_i16(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed16.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed16.set(next);
+        Ok(())
     }
 
-    pub fn get_i32_builtin(&self) -> i32 {
-        self.signed32.get().try_into().unwrap()
+    pub fn decrement_wrapping_i16(&mut self) {
+        let current = self.signed16.get();
+        self.signed16
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
     }
 
-    pub fn set_i32(&mut self, value: I32) {
-        self.signed32.set(value)
+    pub fn decrement_saturating_i16(&mut self) {
+        let current = self.signed16.get();
+        self.signed16
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
     }
 
-    pub fn set_i32_builtin(&mut self, value: i32) {
-        self.signed32.set(value.try_into().unwrap())
+    pub fn add_i16(a: I16, b: I16) -> Result<I16, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
     }
 
-    pub fn increment_i32(&mut self) {
-        let current = self.signed32.get();
-        self.signed32.set(current + Signed::try_from(1).unwrap());
+    pub fn sub_i16(a: I16, b: I16) -> Result<I16, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
     }
 
-    pub fn get_i64(&self) -> I64 {
-        self.signed64.get()
+    pub fn mul_i16(a: I16, b: I16) -> Result<I16, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
     }
 
-    pub fn get_i64_builtin(&self) -> i64 {
-        self.signed64.get().try_into().unwrap()
+    pub fn div_i16(a: I16, b: I16) -> Result<I16, Vec<u8>> {
+        signed::div(a, b)
     }
 
-    pub fn set_i64(&mut self, value: I64) {
-        self.signed64.set(value)
+    pub fn mod_i16(a: I16, b: I16) -> Result<I16, Vec<u8>> {
+        signed::rem(a, b)
     }
 
-    pub fn set_i64_builtin(&mut self, value: i64) {
-        self.signed64.set(value.try_into().unwrap())
+    pub fn exp_i16(base: I16, exponent: U16) -> Result<I16, Vec<u8>> {
+        signed::exp(base, exponent)
     }
 
-    pub fn increment_i64(&mut self) {
-        let current = self.signed64.get();
-        self.signed64.set(current + Signed::try_from(1).unwrap());
+    pub fn shl_i16(a: I16, shift: U256) -> I16 {
+        signed::shl(a, shift)
     }
 
-    pub fn get_i128(&self) -> I128 {
-        self.signed128.get()
+    pub fn shr_i16(a: I16, shift: U256) -> I16 {
+        signed::shr(a, shift)
     }
 
-    pub fn get_i128_builtin(&self) -> i128 {
-        self.signed128.get().try_into().unwrap()
+    pub fn sar_i16(a: I16, shift: U256) -> I16 {
+        signed::sar(a, shift)
     }
 
-    pub fn set_i128(&mut self, value: I128) {
-        self.signed128.set(value)
+    pub fn and_i16(a: I16, b: I16) -> I16 {
+        a & b
     }
 
-    pub fn set_i128_builtin(&mut self, value: i128) {
-        self.signed128.set(value.try_into().unwrap())
+    pub fn or_i16(a: I16, b: I16) -> I16 {
+        a | b
     }
 
-    pub fn increment_i128(&mut self) {
-        let current = self.signed128.get();
-        self.signed128.set(current + Signed::try_from(1).unwrap());
+    pub fn xor_i16(a: I16, b: I16) -> I16 {
+        a ^ b
     }
 
-    pub fn get_i160(&self) -> I160 {
-        self.signed160.get()
+    pub fn not_i16(a: I16) -> I16 {
+        !a
     }
 
-    pub fn set_i160(&mut self, value: I160) {
-        self.signed160.set(value)
+    pub fn lt_i16(a: I16, b: I16) -> bool {
+        a < b
     }
 
-    pub fn increment_i160(&mut self) {
-        let current = self.signed160.get();
-        self.signed160.set(current + Signed::try_from(1).unwrap());
+    pub fn gt_i16(a: I16, b: I16) -> bool {
+        a > b
     }
 
-    pub fn get_i200(&self) -> I200 {
-        self.signed200.get()
+    pub fn eq_i16(a: I16, b: I16) -> bool {
+        a == b
     }
 
-    pub fn set_i200(&mut self, value: I200) {
-        self.signed200.set(value)
+    pub fn ne_i16(a: I16, b: I16) -> bool {
+        a != b
     }
 
-    pub fn increment_i200(&mut self) {
-        let current = self.signed200.get();
-        self.signed200.set(current + Signed::try_from(1).unwrap());
+    pub fn le_i16(a: I16, b: I16) -> bool {
+        a <= b
     }
 
-    pub fn get_i256(&self) -> I256 {
-        self.signed256.get()
+    pub fn ge_i16(a: I16, b: I16) -> bool {
+        a >= b
     }
 
-    pub fn set_i256(&mut self, value: I256) {
-        self.signed256.set(value)
+    pub fn get_i24(&self) -> I24 {
+        self.signed24.get()
     }
 
-    pub fn increment_i256(&mut self) {
-        let current = self.signed256.get();
-        self.signed256.set(current + Signed::try_from(1).unwrap());
+    pub fn set_i24(&mut self, value: I24) {
+        self.signed24.set(value)
     }
 
-    pub fn get_u8(&self) -> U8 {
-        self.unsigned8.get()
+    pub fn echo_i24(value: I24) -> I24 {
+        value
     }
 
-    pub fn get_u8_builtin(&self) -> u8 {
-        self.unsigned8.get().try_into().unwrap()
+    pub fn increment_i24(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i24()
     }
 
-    pub fn set_u8(&mut self, value: U8) {
-        self.unsigned8.set(value)
+    pub fn increment_checked_i24(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed24.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed24.set(next);
+        Ok(())
     }
 
-    pub fn set_u8_builtin(&mut self, value: u8) {
-        self.unsigned8.set(value.try_into().unwrap())
+    pub fn increment_wrapping_i24(&mut self) {
+        let current = self.signed24.get();
+        self.signed24
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
     }
 
-    pub fn increment_u8(&mut self) {
-        let current = self.unsigned8.get();
-        self.unsigned8.set(current + Uint::from(1));
+    pub fn increment_saturating_i24(&mut self) {
+        let current = self.signed24.get();
+        self.signed24
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
     }
 
-    pub fn get_u16(&self) -> U16 {
-        self.unsigned16.get()
+    pub fn decrement_i24(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i24()
     }
 
-    pub fn get_u16_builtin(&self) -> u16 {
-        self.unsigned16.get().try_into().unwrap()
+    pub fn decrement_checked_i24(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed24.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed24.set(next);
+        Ok(())
     }
 
-    pub fn set_u16(&mut self, value: U16) {
-        self.unsigned16.set(value)
+    pub fn decrement_wrapping_i24(&mut self) {
+        let current = self.signed24.get();
+        self.signed24
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
     }
 
-    pub fn set_u16_builtin(&mut self, value: u16) {
-        self.unsigned16.set(value.try_into().unwrap())
+    pub fn decrement_saturating_i24(&mut self) {
+        let current = self.signed24.get();
+        self.signed24
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
     }
 
-    pub fn increment_u16(&mut self) {
-        let current = self.unsigned16.get();
-        self.unsigned16.set(current + Uint::from(1));
+    pub fn add_i24(a: I24, b: I24) -> Result<I24, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
     }
 
-    pub fn get_u24(&self) -> U24 {
-        self.unsigned24.get()
+    pub fn sub_i24(a: I24, b: I24) -> Result<I24, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
     }
 
-    pub fn set_u24(&mut self, value: U24) {
-        self.unsigned24.set(value)
+    pub fn mul_i24(a: I24, b: I24) -> Result<I24, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
     }
 
-    pub fn increment_u24(&mut self) {
-        let current = self.unsigned24.get();
-        self.unsigned24.set(current + Uint::from(1));
+    pub fn div_i24(a: I24, b: I24) -> Result<I24, Vec<u8>> {
+        signed::div(a, b)
     }
 
-    pub fn get_u32(&self) -> U32 {
-        self.unsigned32.get()
+    pub fn mod_i24(a: I24, b: I24) -> Result<I24, Vec<u8>> {
+        signed::rem(a, b)
     }
 
-    pub fn get_u32_builtin(&self) -> u32 {
-        self.unsigned32.get().try_into().unwrap()
+    pub fn exp_i24(base: I24, exponent: U24) -> Result<I24, Vec<u8>> {
+        signed::exp(base, exponent)
     }
 
-    pub fn set_u32(&mut self, value: U32) {
-        self.unsigned32.set(value)
+    pub fn shl_i24(a: I24, shift: U256) -> I24 {
+        signed::shl(a, shift)
     }
 
-    pub fn set_u32_builtin(&mut self, value: u32) {
-        self.unsigned32.set(value.try_into().unwrap())
+    pub fn shr_i24(a: I24, shift: U256) -> I24 {
+        signed::shr(a, shift)
     }
 
-    pub fn increment_u32(&mut self) {
-        let current = self.unsigned32.get();
-        self.unsigned32.set(current + Uint::from(1));
+    pub fn sar_i24(a: I24, shift: U256) -> I24 {
+        signed::sar(a, shift)
     }
 
-    pub fn get_u64(&self) -> U64 {
-        self.unsigned64.get()
+    pub fn and_i24(a: I24, b: I24) -> I24 {
+        a & b
     }
 
-    pub fn get_u64_builtin(&self) -> u64 {
-        self.unsigned64.get().try_into().unwrap()
+    pub fn or_i24(a: I24, b: I24) -> I24 {
+        a | b
     }
 
-    pub fn set_u64(&mut self, value: U64) {
-        self.unsigned64.set(value)
+    pub fn xor_i24(a: I24, b: I24) -> I24 {
+        a ^ b
     }
 
-    pub fn set_u64_builtin(&mut self, value: u64) {
-        self.unsigned64.set(value.try_into().unwrap())
+    pub fn not_i24(a: I24) -> I24 {
+        !a
     }
 
-    pub fn increment_u64(&mut self) {
-        let current = self.unsigned64.get();
-        self.unsigned64.set(current + Uint::from(1));
+    pub fn lt_i24(a: I24, b: I24) -> bool {
+        a < b
     }
 
-    pub fn get_u128(&self) -> U128 {
-        self.unsigned128.get()
+    pub fn gt_i24(a: I24, b: I24) -> bool {
+        a > b
     }
 
-    pub fn get_u128_builtin(&self) -> u128 {
-        self.unsigned128.get().try_into().unwrap()
+    pub fn eq_i24(a: I24, b: I24) -> bool {
+        a == b
     }
 
-    pub fn set_u128(&mut self, value: U128) {
-        self.unsigned128.set(value)
+    pub fn ne_i24(a: I24, b: I24) -> bool {
+        a != b
     }
 
-    pub fn set_u128_builtin(&mut self, value: u128) {
-        self.unsigned128.set(value.try_into().unwrap())
+    pub fn le_i24(a: I24, b: I24) -> bool {
+        a <= b
     }
 
-    pub fn increment_u128(&mut self) {
-        let current = self.unsigned128.get();
-        self.unsigned128.set(current + Uint::from(1));
+    pub fn ge_i24(a: I24, b: I24) -> bool {
+        a >= b
     }
 
-    pub fn get_u160(&self) -> U160 {
-        self.unsigned160.get()
+    pub fn get_i32(&self) -> I32 {
+        self.signed32.get()
     }
 
-    pub fn set_u160(&mut self, value: U160) {
-        self.unsigned160.set(value)
+    pub fn get_i32_builtin(&self) -> i32 {
+        self.signed32.get().try_into().unwrap()
     }
 
-    pub fn increment_u160(&mut self) {
-        let current = self.unsigned160.get();
-        self.unsigned160.set(current + Uint::from(1));
+    pub fn set_i32(&mut self, value: I32) {
+        self.signed32.set(value)
     }
 
-    pub fn get_u200(&self) -> U200 {
-        self.unsigned200.get()
+    pub fn echo_i32(value: I32) -> I32 {
+        value
     }
 
-    pub fn set_u200(&mut self, value: U200) {
-        self.unsigned200.set(value)
+    pub fn set_i32_builtin(&mut self, value: i32) {
+        self.signed32.set(value.try_into().unwrap())
     }
 
-    pub fn increment_u200(&mut self) {
-        let current = self.unsigned200.get();
-        self.unsigned200.set(current + Uint::from(1));
+    pub fn increment_i32(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i32()
     }
 
-    pub fn get_u256(&self) -> U256 {
-        self.unsigned256.get()
+    pub fn increment_checked_i32(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed32.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed32.set(next);
+        Ok(())
     }
 
-    pub fn set_u256(&mut self, value: U256) {
-        self.unsigned256.set(value)
+    pub fn increment_wrapping_i32(&mut self) {
+        let current = self.signed32.get();
+        self.signed32
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
     }
 
-    pub fn increment_u256(&mut self) {
-        let current = self.unsigned256.get();
-        self.unsigned256.set(current + Uint::from(1));
+    pub fn increment_saturating_i32(&mut self) {
+        let current = self.signed32.get();
+        self.signed32
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i32(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i32()
+    }
+
+    pub fn decrement_checked_i32(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed32.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed32.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i32(&mut self) {
+        let current = self.signed32.get();
+        self.signed32
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i32(&mut self) {
+        let current = self.signed32.get();
+        self.signed32
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i32(a: I32, b: I32) -> Result<I32, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i32(a: I32, b: I32) -> Result<I32, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i32(a: I32, b: I32) -> Result<I32, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i32(a: I32, b: I32) -> Result<I32, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i32(a: I32, b: I32) -> Result<I32, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i32(base: I32, exponent: U32) -> Result<I32, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i32(a: I32, shift: U256) -> I32 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i32(a: I32, shift: U256) -> I32 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i32(a: I32, shift: U256) -> I32 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i32(a: I32, b: I32) -> I32 {
+        a & b
+    }
+
+    pub fn or_i32(a: I32, b: I32) -> I32 {
+        a | b
+    }
+
+    pub fn xor_i32(a: I32, b: I32) -> I32 {
+        a ^ b
+    }
+
+    pub fn not_i32(a: I32) -> I32 {
+        !a
+    }
+
+    pub fn lt_i32(a: I32, b: I32) -> bool {
+        a < b
+    }
+
+    pub fn gt_i32(a: I32, b: I32) -> bool {
+        a > b
+    }
+
+    pub fn eq_i32(a: I32, b: I32) -> bool {
+        a == b
+    }
+
+    pub fn ne_i32(a: I32, b: I32) -> bool {
+        a != b
+    }
+
+    pub fn le_i32(a: I32, b: I32) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i32(a: I32, b: I32) -> bool {
+        a >= b
+    }
+
+    pub fn get_i64(&self) -> I64 {
+        self.signed64.get()
+    }
+
+    pub fn get_i64_builtin(&self) -> i64 {
+        self.signed64.get().try_into().unwrap()
+    }
+
+    pub fn set_i64(&mut self, value: I64) {
+        self.signed64.set(value)
+    }
+
+    pub fn echo_i64(value: I64) -> I64 {
+        value
+    }
+
+    pub fn set_i64_builtin(&mut self, value: i64) {
+        self.signed64.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_i64(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i64()
+    }
+
+    pub fn increment_checked_i64(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed64.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed64.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_i64(&mut self) {
+        let current = self.signed64.get();
+        self.signed64
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn increment_saturating_i64(&mut self) {
+        let current = self.signed64.get();
+        self.signed64
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i64(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i64()
+    }
+
+    pub fn decrement_checked_i64(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed64.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed64.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i64(&mut self) {
+        let current = self.signed64.get();
+        self.signed64
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i64(&mut self) {
+        let current = self.signed64.get();
+        self.signed64
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i64(a: I64, b: I64) -> Result<I64, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i64(a: I64, b: I64) -> Result<I64, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i64(a: I64, b: I64) -> Result<I64, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i64(a: I64, b: I64) -> Result<I64, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i64(a: I64, b: I64) -> Result<I64, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i64(base: I64, exponent: U64) -> Result<I64, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i64(a: I64, shift: U256) -> I64 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i64(a: I64, shift: U256) -> I64 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i64(a: I64, shift: U256) -> I64 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i64(a: I64, b: I64) -> I64 {
+        a & b
+    }
+
+    pub fn or_i64(a: I64, b: I64) -> I64 {
+        a | b
+    }
+
+    pub fn xor_i64(a: I64, b: I64) -> I64 {
+        a ^ b
+    }
+
+    pub fn not_i64(a: I64) -> I64 {
+        !a
+    }
+
+    pub fn lt_i64(a: I64, b: I64) -> bool {
+        a < b
+    }
+
+    pub fn gt_i64(a: I64, b: I64) -> bool {
+        a > b
+    }
+
+    pub fn eq_i64(a: I64, b: I64) -> bool {
+        a == b
+    }
+
+    pub fn ne_i64(a: I64, b: I64) -> bool {
+        a != b
+    }
+
+    pub fn le_i64(a: I64, b: I64) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i64(a: I64, b: I64) -> bool {
+        a >= b
+    }
+
+    pub fn get_i128(&self) -> I128 {
+        self.signed128.get()
+    }
+
+    pub fn get_i128_builtin(&self) -> i128 {
+        self.signed128.get().try_into().unwrap()
+    }
+
+    pub fn set_i128(&mut self, value: I128) {
+        self.signed128.set(value)
+    }
+
+    pub fn echo_i128(value: I128) -> I128 {
+        value
+    }
+
+    pub fn set_i128_builtin(&mut self, value: i128) {
+        self.signed128.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_i128(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i128()
+    }
+
+    pub fn increment_checked_i128(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed128.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed128.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_i128(&mut self) {
+        let current = self.signed128.get();
+        self.signed128
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn increment_saturating_i128(&mut self) {
+        let current = self.signed128.get();
+        self.signed128
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i128(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i128()
+    }
+
+    pub fn decrement_checked_i128(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed128.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed128.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i128(&mut self) {
+        let current = self.signed128.get();
+        self.signed128
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i128(&mut self) {
+        let current = self.signed128.get();
+        self.signed128
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i128(a: I128, b: I128) -> Result<I128, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i128(a: I128, b: I128) -> Result<I128, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i128(a: I128, b: I128) -> Result<I128, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i128(a: I128, b: I128) -> Result<I128, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i128(a: I128, b: I128) -> Result<I128, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i128(base: I128, exponent: U128) -> Result<I128, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i128(a: I128, shift: U256) -> I128 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i128(a: I128, shift: U256) -> I128 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i128(a: I128, shift: U256) -> I128 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i128(a: I128, b: I128) -> I128 {
+        a & b
+    }
+
+    pub fn or_i128(a: I128, b: I128) -> I128 {
+        a | b
+    }
+
+    pub fn xor_i128(a: I128, b: I128) -> I128 {
+        a ^ b
+    }
+
+    pub fn not_i128(a: I128) -> I128 {
+        !a
+    }
+
+    pub fn lt_i128(a: I128, b: I128) -> bool {
+        a < b
+    }
+
+    pub fn gt_i128(a: I128, b: I128) -> bool {
+        a > b
+    }
+
+    pub fn eq_i128(a: I128, b: I128) -> bool {
+        a == b
+    }
+
+    pub fn ne_i128(a: I128, b: I128) -> bool {
+        a != b
+    }
+
+    pub fn le_i128(a: I128, b: I128) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i128(a: I128, b: I128) -> bool {
+        a >= b
+    }
+
+    pub fn get_i160(&self) -> I160 {
+        self.signed160.get()
+    }
+
+    pub fn set_i160(&mut self, value: I160) {
+        self.signed160.set(value)
+    }
+
+    pub fn echo_i160(value: I160) -> I160 {
+        value
+    }
+
+    pub fn increment_i160(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i160()
+    }
+
+    pub fn increment_checked_i160(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed160.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed160.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_i160(&mut self) {
+        let current = self.signed160.get();
+        self.signed160
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn increment_saturating_i160(&mut self) {
+        let current = self.signed160.get();
+        self.signed160
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i160(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i160()
+    }
+
+    pub fn decrement_checked_i160(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed160.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed160.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i160(&mut self) {
+        let current = self.signed160.get();
+        self.signed160
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i160(&mut self) {
+        let current = self.signed160.get();
+        self.signed160
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i160(a: I160, b: I160) -> Result<I160, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i160(a: I160, b: I160) -> Result<I160, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i160(a: I160, b: I160) -> Result<I160, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i160(a: I160, b: I160) -> Result<I160, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i160(a: I160, b: I160) -> Result<I160, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i160(base: I160, exponent: U160) -> Result<I160, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i160(a: I160, shift: U256) -> I160 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i160(a: I160, shift: U256) -> I160 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i160(a: I160, shift: U256) -> I160 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i160(a: I160, b: I160) -> I160 {
+        a & b
+    }
+
+    pub fn or_i160(a: I160, b: I160) -> I160 {
+        a | b
+    }
+
+    pub fn xor_i160(a: I160, b: I160) -> I160 {
+        a ^ b
+    }
+
+    pub fn not_i160(a: I160) -> I160 {
+        !a
+    }
+
+    pub fn lt_i160(a: I160, b: I160) -> bool {
+        a < b
+    }
+
+    pub fn gt_i160(a: I160, b: I160) -> bool {
+        a > b
+    }
+
+    pub fn eq_i160(a: I160, b: I160) -> bool {
+        a == b
+    }
+
+    pub fn ne_i160(a: I160, b: I160) -> bool {
+        a != b
+    }
+
+    pub fn le_i160(a: I160, b: I160) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i160(a: I160, b: I160) -> bool {
+        a >= b
+    }
+
+    pub fn get_i200(&self) -> I200 {
+        self.signed200.get()
+    }
+
+    pub fn set_i200(&mut self, value: I200) {
+        self.signed200.set(value)
+    }
+
+    pub fn echo_i200(value: I200) -> I200 {
+        value
+    }
+
+    pub fn increment_i200(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i200()
+    }
+
+    pub fn increment_checked_i200(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed200.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed200.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_i200(&mut self) {
+        let current = self.signed200.get();
+        self.signed200
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn increment_saturating_i200(&mut self) {
+        let current = self.signed200.get();
+        self.signed200
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i200(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i200()
+    }
+
+    pub fn decrement_checked_i200(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed200.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed200.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i200(&mut self) {
+        let current = self.signed200.get();
+        self.signed200
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i200(&mut self) {
+        let current = self.signed200.get();
+        self.signed200
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i200(a: I200, b: I200) -> Result<I200, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i200(a: I200, b: I200) -> Result<I200, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i200(a: I200, b: I200) -> Result<I200, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i200(a: I200, b: I200) -> Result<I200, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i200(a: I200, b: I200) -> Result<I200, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i200(base: I200, exponent: U200) -> Result<I200, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i200(a: I200, shift: U256) -> I200 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i200(a: I200, shift: U256) -> I200 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i200(a: I200, shift: U256) -> I200 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i200(a: I200, b: I200) -> I200 {
+        a & b
+    }
+
+    pub fn or_i200(a: I200, b: I200) -> I200 {
+        a | b
+    }
+
+    pub fn xor_i200(a: I200, b: I200) -> I200 {
+        a ^ b
+    }
+
+    pub fn not_i200(a: I200) -> I200 {
+        !a
+    }
+
+    pub fn lt_i200(a: I200, b: I200) -> bool {
+        a < b
+    }
+
+    pub fn gt_i200(a: I200, b: I200) -> bool {
+        a > b
+    }
+
+    pub fn eq_i200(a: I200, b: I200) -> bool {
+        a == b
+    }
+
+    pub fn ne_i200(a: I200, b: I200) -> bool {
+        a != b
+    }
+
+    pub fn le_i200(a: I200, b: I200) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i200(a: I200, b: I200) -> bool {
+        a >= b
+    }
+
+    pub fn get_i256(&self) -> I256 {
+        self.signed256.get()
+    }
+
+    pub fn set_i256(&mut self, value: I256) {
+        self.signed256.set(value)
+    }
+
+    pub fn increment_i256(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_i256()
+    }
+
+    pub fn increment_checked_i256(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed256.get();
+        let next = current
+            .checked_add(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed256.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_i256(&mut self) {
+        let current = self.signed256.get();
+        self.signed256
+            .set(current.wrapping_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn increment_saturating_i256(&mut self) {
+        let current = self.signed256.get();
+        self.signed256
+            .set(current.saturating_add(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_i256(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_i256()
+    }
+
+    pub fn decrement_checked_i256(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.signed256.get();
+        let next = current
+            .checked_sub(Signed::try_from(1).unwrap())
+            .ok_or_else(arithmetic_overflow)?;
+        self.signed256.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_i256(&mut self) {
+        let current = self.signed256.get();
+        self.signed256
+            .set(current.wrapping_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn decrement_saturating_i256(&mut self) {
+        let current = self.signed256.get();
+        self.signed256
+            .set(current.saturating_sub(Signed::try_from(1).unwrap()));
+    }
+
+    pub fn add_i256(a: I256, b: I256) -> Result<I256, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_i256(a: I256, b: I256) -> Result<I256, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_i256(a: I256, b: I256) -> Result<I256, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_i256(a: I256, b: I256) -> Result<I256, Vec<u8>> {
+        signed::div(a, b)
+    }
+
+    pub fn mod_i256(a: I256, b: I256) -> Result<I256, Vec<u8>> {
+        signed::rem(a, b)
+    }
+
+    pub fn exp_i256(base: I256, exponent: U256) -> Result<I256, Vec<u8>> {
+        signed::exp(base, exponent)
+    }
+
+    pub fn shl_i256(a: I256, shift: U256) -> I256 {
+        signed::shl(a, shift)
+    }
+
+    pub fn shr_i256(a: I256, shift: U256) -> I256 {
+        signed::shr(a, shift)
+    }
+
+    pub fn sar_i256(a: I256, shift: U256) -> I256 {
+        signed::sar(a, shift)
+    }
+
+    pub fn and_i256(a: I256, b: I256) -> I256 {
+        a & b
+    }
+
+    pub fn or_i256(a: I256, b: I256) -> I256 {
+        a | b
+    }
+
+    pub fn xor_i256(a: I256, b: I256) -> I256 {
+        a ^ b
+    }
+
+    pub fn not_i256(a: I256) -> I256 {
+        !a
+    }
+
+    pub fn lt_i256(a: I256, b: I256) -> bool {
+        a < b
+    }
+
+    pub fn gt_i256(a: I256, b: I256) -> bool {
+        a > b
+    }
+
+    pub fn eq_i256(a: I256, b: I256) -> bool {
+        a == b
+    }
+
+    pub fn ne_i256(a: I256, b: I256) -> bool {
+        a != b
+    }
+
+    pub fn le_i256(a: I256, b: I256) -> bool {
+        a <= b
+    }
+
+    pub fn ge_i256(a: I256, b: I256) -> bool {
+        a >= b
+    }
+
+    pub fn get_u8(&self) -> U8 {
+        self.unsigned8.get()
+    }
+
+    pub fn get_u8_builtin(&self) -> u8 {
+        self.unsigned8.get().try_into().unwrap()
+    }
+
+    pub fn set_u8(&mut self, value: U8) {
+        self.unsigned8.set(value)
+    }
+
+    pub fn echo_u8(value: U8) -> U8 {
+        value
+    }
+
+    pub fn set_u8_builtin(&mut self, value: u8) {
+        self.unsigned8.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_u8(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u8()
+    }
+
+    pub fn increment_checked_u8(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned8.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned8.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u8(&mut self) {
+        let current = self.unsigned8.get();
+        self.unsigned8.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u8(&mut self) {
+        let current = self.unsigned8.get();
+        self.unsigned8.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u8(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u8()
+    }
+
+    pub fn decrement_checked_u8(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned8.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned8.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u8(&mut self) {
+        let current = self.unsigned8.get();
+        self.unsigned8.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u8(&mut self) {
+        let current = self.unsigned8.get();
+        self.unsigned8.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u8(a: U8, b: U8) -> Result<U8, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u8(a: U8, b: U8) -> Result<U8, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u8(a: U8, b: U8) -> Result<U8, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u8(a: U8, b: U8) -> Result<U8, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u8(a: U8, b: U8) -> Result<U8, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u8(base: U8, exponent: U8) -> Result<U8, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u8(a: U8, shift: U256) -> U8 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u8(a: U8, shift: U256) -> U8 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u8(a: U8, b: U8) -> U8 {
+        a & b
+    }
+
+    pub fn or_u8(a: U8, b: U8) -> U8 {
+        a | b
+    }
+
+    pub fn xor_u8(a: U8, b: U8) -> U8 {
+        a ^ b
+    }
+
+    pub fn not_u8(a: U8) -> U8 {
+        !a
+    }
+
+    pub fn lt_u8(a: U8, b: U8) -> bool {
+        a < b
+    }
+
+    pub fn gt_u8(a: U8, b: U8) -> bool {
+        a > b
+    }
+
+    pub fn eq_u8(a: U8, b: U8) -> bool {
+        a == b
+    }
+
+    pub fn ne_u8(a: U8, b: U8) -> bool {
+        a != b
+    }
+
+    pub fn le_u8(a: U8, b: U8) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u8(a: U8, b: U8) -> bool {
+        a >= b
+    }
+
+    pub fn get_u16(&self) -> U16 {
+        self.unsigned16.get()
+    }
+
+    pub fn get_u16_builtin(&self) -> u16 {
+        self.unsigned16.get().try_into().unwrap()
+    }
+
+    pub fn set_u16(&mut self, value: U16) {
+        self.unsigned16.set(value)
+    }
+
+    pub fn echo_u16(value: U16) -> U16 {
+        value
+    }
+
+    pub fn set_u16_builtin(&mut self, value: u16) {
+        self.unsigned16.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_u16(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u16()
+    }
+
+    pub fn increment_checked_u16(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned16.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned16.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u16(&mut self) {
+        let current = self.unsigned16.get();
+        self.unsigned16.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u16(&mut self) {
+        let current = self.unsigned16.get();
+        self.unsigned16.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u16(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u16()
+    }
+
+    pub fn decrement_checked_u16(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned16.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned16.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u16(&mut self) {
+        let current = self.unsigned16.get();
+        self.unsigned16.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u16(&mut self) {
+        let current = self.unsigned16.get();
+        self.unsigned16.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u16(a: U16, b: U16) -> Result<U16, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u16(a: U16, b: U16) -> Result<U16, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u16(a: U16, b: U16) -> Result<U16, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u16(a: U16, b: U16) -> Result<U16, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u16(a: U16, b: U16) -> Result<U16, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u16(base: U16, exponent: U16) -> Result<U16, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u16(a: U16, shift: U256) -> U16 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u16(a: U16, shift: U256) -> U16 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u16(a: U16, b: U16) -> U16 {
+        a & b
+    }
+
+    pub fn or_u16(a: U16, b: U16) -> U16 {
+        a | b
+    }
+
+    pub fn xor_u16(a: U16, b: U16) -> U16 {
+        a ^ b
+    }
+
+    pub fn not_u16(a: U16) -> U16 {
+        !a
+    }
+
+    pub fn lt_u16(a: U16, b: U16) -> bool {
+        a < b
+    }
+
+    pub fn gt_u16(a: U16, b: U16) -> bool {
+        a > b
+    }
+
+    pub fn eq_u16(a: U16, b: U16) -> bool {
+        a == b
+    }
+
+    pub fn ne_u16(a: U16, b: U16) -> bool {
+        a != b
+    }
+
+    pub fn le_u16(a: U16, b: U16) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u16(a: U16, b: U16) -> bool {
+        a >= b
+    }
+
+    pub fn get_u24(&self) -> U24 {
+        self.unsigned24.get()
+    }
+
+    pub fn set_u24(&mut self, value: U24) {
+        self.unsigned24.set(value)
+    }
+
+    pub fn echo_u24(value: U24) -> U24 {
+        value
+    }
+
+    pub fn increment_u24(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u24()
+    }
+
+    pub fn increment_checked_u24(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned24.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned24.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u24(&mut self) {
+        let current = self.unsigned24.get();
+        self.unsigned24.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u24(&mut self) {
+        let current = self.unsigned24.get();
+        self.unsigned24.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u24(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u24()
+    }
+
+    pub fn decrement_checked_u24(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned24.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned24.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u24(&mut self) {
+        let current = self.unsigned24.get();
+        self.unsigned24.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u24(&mut self) {
+        let current = self.unsigned24.get();
+        self.unsigned24.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u24(a: U24, b: U24) -> Result<U24, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u24(a: U24, b: U24) -> Result<U24, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u24(a: U24, b: U24) -> Result<U24, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u24(a: U24, b: U24) -> Result<U24, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u24(a: U24, b: U24) -> Result<U24, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u24(base: U24, exponent: U24) -> Result<U24, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u24(a: U24, shift: U256) -> U24 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u24(a: U24, shift: U256) -> U24 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u24(a: U24, b: U24) -> U24 {
+        a & b
+    }
+
+    pub fn or_u24(a: U24, b: U24) -> U24 {
+        a | b
+    }
+
+    pub fn xor_u24(a: U24, b: U24) -> U24 {
+        a ^ b
+    }
+
+    pub fn not_u24(a: U24) -> U24 {
+        !a
+    }
+
+    pub fn lt_u24(a: U24, b: U24) -> bool {
+        a < b
+    }
+
+    pub fn gt_u24(a: U24, b: U24) -> bool {
+        a > b
+    }
+
+    pub fn eq_u24(a: U24, b: U24) -> bool {
+        a == b
+    }
+
+    pub fn ne_u24(a: U24, b: U24) -> bool {
+        a != b
+    }
+
+    pub fn le_u24(a: U24, b: U24) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u24(a: U24, b: U24) -> bool {
+        a >= b
+    }
+
+    pub fn get_u32(&self) -> U32 {
+        self.unsigned32.get()
+    }
+
+    pub fn get_u32_builtin(&self) -> u32 {
+        self.unsigned32.get().try_into().unwrap()
+    }
+
+    pub fn set_u32(&mut self, value: U32) {
+        self.unsigned32.set(value)
+    }
+
+    pub fn echo_u32(value: U32) -> U32 {
+        value
+    }
+
+    pub fn set_u32_builtin(&mut self, value: u32) {
+        self.unsigned32.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_u32(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u32()
+    }
+
+    pub fn increment_checked_u32(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned32.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned32.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u32(&mut self) {
+        let current = self.unsigned32.get();
+        self.unsigned32.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u32(&mut self) {
+        let current = self.unsigned32.get();
+        self.unsigned32.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u32(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u32()
+    }
+
+    pub fn decrement_checked_u32(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned32.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned32.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u32(&mut self) {
+        let current = self.unsigned32.get();
+        self.unsigned32.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u32(&mut self) {
+        let current = self.unsigned32.get();
+        self.unsigned32.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u32(a: U32, b: U32) -> Result<U32, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u32(a: U32, b: U32) -> Result<U32, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u32(a: U32, b: U32) -> Result<U32, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u32(a: U32, b: U32) -> Result<U32, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u32(a: U32, b: U32) -> Result<U32, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u32(base: U32, exponent: U32) -> Result<U32, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u32(a: U32, shift: U256) -> U32 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u32(a: U32, shift: U256) -> U32 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u32(a: U32, b: U32) -> U32 {
+        a & b
+    }
+
+    pub fn or_u32(a: U32, b: U32) -> U32 {
+        a | b
+    }
+
+    pub fn xor_u32(a: U32, b: U32) -> U32 {
+        a ^ b
+    }
+
+    pub fn not_u32(a: U32) -> U32 {
+        !a
+    }
+
+    pub fn lt_u32(a: U32, b: U32) -> bool {
+        a < b
+    }
+
+    pub fn gt_u32(a: U32, b: U32) -> bool {
+        a > b
+    }
+
+    pub fn eq_u32(a: U32, b: U32) -> bool {
+        a == b
+    }
+
+    pub fn ne_u32(a: U32, b: U32) -> bool {
+        a != b
+    }
+
+    pub fn le_u32(a: U32, b: U32) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u32(a: U32, b: U32) -> bool {
+        a >= b
+    }
+
+    pub fn get_u64(&self) -> U64 {
+        self.unsigned64.get()
+    }
+
+    pub fn get_u64_builtin(&self) -> u64 {
+        self.unsigned64.get().try_into().unwrap()
+    }
+
+    pub fn set_u64(&mut self, value: U64) {
+        self.unsigned64.set(value)
+    }
+
+    pub fn echo_u64(value: U64) -> U64 {
+        value
+    }
+
+    pub fn set_u64_builtin(&mut self, value: u64) {
+        self.unsigned64.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_u64(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u64()
+    }
+
+    pub fn increment_checked_u64(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned64.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned64.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u64(&mut self) {
+        let current = self.unsigned64.get();
+        self.unsigned64.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u64(&mut self) {
+        let current = self.unsigned64.get();
+        self.unsigned64.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u64(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u64()
+    }
+
+    pub fn decrement_checked_u64(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned64.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned64.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u64(&mut self) {
+        let current = self.unsigned64.get();
+        self.unsigned64.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u64(&mut self) {
+        let current = self.unsigned64.get();
+        self.unsigned64.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u64(a: U64, b: U64) -> Result<U64, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u64(a: U64, b: U64) -> Result<U64, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u64(a: U64, b: U64) -> Result<U64, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u64(a: U64, b: U64) -> Result<U64, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u64(a: U64, b: U64) -> Result<U64, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u64(base: U64, exponent: U64) -> Result<U64, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u64(a: U64, shift: U256) -> U64 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u64(a: U64, shift: U256) -> U64 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u64(a: U64, b: U64) -> U64 {
+        a & b
+    }
+
+    pub fn or_u64(a: U64, b: U64) -> U64 {
+        a | b
+    }
+
+    pub fn xor_u64(a: U64, b: U64) -> U64 {
+        a ^ b
+    }
+
+    pub fn not_u64(a: U64) -> U64 {
+        !a
+    }
+
+    pub fn lt_u64(a: U64, b: U64) -> bool {
+        a < b
+    }
+
+    pub fn gt_u64(a: U64, b: U64) -> bool {
+        a > b
+    }
+
+    pub fn eq_u64(a: U64, b: U64) -> bool {
+        a == b
+    }
+
+    pub fn ne_u64(a: U64, b: U64) -> bool {
+        a != b
+    }
+
+    pub fn le_u64(a: U64, b: U64) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u64(a: U64, b: U64) -> bool {
+        a >= b
+    }
+
+    pub fn get_u128(&self) -> U128 {
+        self.unsigned128.get()
+    }
+
+    pub fn get_u128_builtin(&self) -> u128 {
+        self.unsigned128.get().try_into().unwrap()
+    }
+
+    pub fn set_u128(&mut self, value: U128) {
+        self.unsigned128.set(value)
+    }
+
+    pub fn echo_u128(value: U128) -> U128 {
+        value
+    }
+
+    pub fn set_u128_builtin(&mut self, value: u128) {
+        self.unsigned128.set(value.try_into().unwrap())
+    }
+
+    pub fn increment_u128(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u128()
+    }
+
+    pub fn increment_checked_u128(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned128.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned128.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u128(&mut self) {
+        let current = self.unsigned128.get();
+        self.unsigned128.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u128(&mut self) {
+        let current = self.unsigned128.get();
+        self.unsigned128.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u128(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u128()
+    }
+
+    pub fn decrement_checked_u128(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned128.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned128.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u128(&mut self) {
+        let current = self.unsigned128.get();
+        self.unsigned128.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u128(&mut self) {
+        let current = self.unsigned128.get();
+        self.unsigned128.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u128(a: U128, b: U128) -> Result<U128, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u128(a: U128, b: U128) -> Result<U128, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u128(a: U128, b: U128) -> Result<U128, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u128(a: U128, b: U128) -> Result<U128, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u128(a: U128, b: U128) -> Result<U128, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u128(base: U128, exponent: U128) -> Result<U128, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u128(a: U128, shift: U256) -> U128 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u128(a: U128, shift: U256) -> U128 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u128(a: U128, b: U128) -> U128 {
+        a & b
+    }
+
+    pub fn or_u128(a: U128, b: U128) -> U128 {
+        a | b
+    }
+
+    pub fn xor_u128(a: U128, b: U128) -> U128 {
+        a ^ b
+    }
+
+    pub fn not_u128(a: U128) -> U128 {
+        !a
+    }
+
+    pub fn lt_u128(a: U128, b: U128) -> bool {
+        a < b
+    }
+
+    pub fn gt_u128(a: U128, b: U128) -> bool {
+        a > b
+    }
+
+    pub fn eq_u128(a: U128, b: U128) -> bool {
+        a == b
+    }
+
+    pub fn ne_u128(a: U128, b: U128) -> bool {
+        a != b
+    }
+
+    pub fn le_u128(a: U128, b: U128) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u128(a: U128, b: U128) -> bool {
+        a >= b
+    }
+
+    pub fn get_u160(&self) -> U160 {
+        self.unsigned160.get()
+    }
+
+    pub fn set_u160(&mut self, value: U160) {
+        self.unsigned160.set(value)
+    }
+
+    pub fn echo_u160(value: U160) -> U160 {
+        value
+    }
+
+    pub fn increment_u160(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u160()
+    }
+
+    pub fn increment_checked_u160(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned160.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned160.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u160(&mut self) {
+        let current = self.unsigned160.get();
+        self.unsigned160.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u160(&mut self) {
+        let current = self.unsigned160.get();
+        self.unsigned160.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u160(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u160()
+    }
+
+    pub fn decrement_checked_u160(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned160.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned160.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u160(&mut self) {
+        let current = self.unsigned160.get();
+        self.unsigned160.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u160(&mut self) {
+        let current = self.unsigned160.get();
+        self.unsigned160.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u160(a: U160, b: U160) -> Result<U160, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u160(a: U160, b: U160) -> Result<U160, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u160(a: U160, b: U160) -> Result<U160, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u160(a: U160, b: U160) -> Result<U160, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u160(a: U160, b: U160) -> Result<U160, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u160(base: U160, exponent: U160) -> Result<U160, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u160(a: U160, shift: U256) -> U160 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u160(a: U160, shift: U256) -> U160 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u160(a: U160, b: U160) -> U160 {
+        a & b
+    }
+
+    pub fn or_u160(a: U160, b: U160) -> U160 {
+        a | b
+    }
+
+    pub fn xor_u160(a: U160, b: U160) -> U160 {
+        a ^ b
+    }
+
+    pub fn not_u160(a: U160) -> U160 {
+        !a
+    }
+
+    pub fn lt_u160(a: U160, b: U160) -> bool {
+        a < b
+    }
+
+    pub fn gt_u160(a: U160, b: U160) -> bool {
+        a > b
+    }
+
+    pub fn eq_u160(a: U160, b: U160) -> bool {
+        a == b
+    }
+
+    pub fn ne_u160(a: U160, b: U160) -> bool {
+        a != b
+    }
+
+    pub fn le_u160(a: U160, b: U160) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u160(a: U160, b: U160) -> bool {
+        a >= b
+    }
+
+    pub fn get_u200(&self) -> U200 {
+        self.unsigned200.get()
+    }
+
+    pub fn set_u200(&mut self, value: U200) {
+        self.unsigned200.set(value)
+    }
+
+    pub fn echo_u200(value: U200) -> U200 {
+        value
+    }
+
+    pub fn increment_u200(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u200()
+    }
+
+    pub fn increment_checked_u200(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned200.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned200.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u200(&mut self) {
+        let current = self.unsigned200.get();
+        self.unsigned200.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u200(&mut self) {
+        let current = self.unsigned200.get();
+        self.unsigned200.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u200(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u200()
+    }
+
+    pub fn decrement_checked_u200(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned200.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned200.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u200(&mut self) {
+        let current = self.unsigned200.get();
+        self.unsigned200.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u200(&mut self) {
+        let current = self.unsigned200.get();
+        self.unsigned200.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u200(a: U200, b: U200) -> Result<U200, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u200(a: U200, b: U200) -> Result<U200, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u200(a: U200, b: U200) -> Result<U200, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u200(a: U200, b: U200) -> Result<U200, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u200(a: U200, b: U200) -> Result<U200, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u200(base: U200, exponent: U200) -> Result<U200, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u200(a: U200, shift: U256) -> U200 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u200(a: U200, shift: U256) -> U200 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u200(a: U200, b: U200) -> U200 {
+        a & b
+    }
+
+    pub fn or_u200(a: U200, b: U200) -> U200 {
+        a | b
+    }
+
+    pub fn xor_u200(a: U200, b: U200) -> U200 {
+        a ^ b
+    }
+
+    pub fn not_u200(a: U200) -> U200 {
+        !a
+    }
+
+    pub fn lt_u200(a: U200, b: U200) -> bool {
+        a < b
+    }
+
+    pub fn gt_u200(a: U200, b: U200) -> bool {
+        a > b
+    }
+
+    pub fn eq_u200(a: U200, b: U200) -> bool {
+        a == b
+    }
+
+    pub fn ne_u200(a: U200, b: U200) -> bool {
+        a != b
+    }
+
+    pub fn le_u200(a: U200, b: U200) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u200(a: U200, b: U200) -> bool {
+        a >= b
+    }
+
+    pub fn get_u256(&self) -> U256 {
+        self.unsigned256.get()
+    }
+
+    pub fn set_u256(&mut self, value: U256) {
+        self.unsigned256.set(value)
+    }
+
+    pub fn increment_u256(&mut self) -> Result<(), Vec<u8>> {
+        self.increment_checked_u256()
+    }
+
+    pub fn increment_checked_u256(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned256.get();
+        let next = current
+            .checked_add(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned256.set(next);
+        Ok(())
+    }
+
+    pub fn increment_wrapping_u256(&mut self) {
+        let current = self.unsigned256.get();
+        self.unsigned256.set(current.wrapping_add(Uint::from(1)));
+    }
+
+    pub fn increment_saturating_u256(&mut self) {
+        let current = self.unsigned256.get();
+        self.unsigned256.set(current.saturating_add(Uint::from(1)));
+    }
+
+    pub fn decrement_u256(&mut self) -> Result<(), Vec<u8>> {
+        self.decrement_checked_u256()
+    }
+
+    pub fn decrement_checked_u256(&mut self) -> Result<(), Vec<u8>> {
+        let current = self.unsigned256.get();
+        let next = current
+            .checked_sub(Uint::from(1))
+            .ok_or_else(arithmetic_overflow)?;
+        self.unsigned256.set(next);
+        Ok(())
+    }
+
+    pub fn decrement_wrapping_u256(&mut self) {
+        let current = self.unsigned256.get();
+        self.unsigned256.set(current.wrapping_sub(Uint::from(1)));
+    }
+
+    pub fn decrement_saturating_u256(&mut self) {
+        let current = self.unsigned256.get();
+        self.unsigned256.set(current.saturating_sub(Uint::from(1)));
+    }
+
+    pub fn add_u256(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+        a.checked_add(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn sub_u256(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+        a.checked_sub(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn mul_u256(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+        a.checked_mul(b).ok_or_else(arithmetic_overflow)
+    }
+
+    pub fn div_u256(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+        unsigned::div(a, b)
+    }
+
+    pub fn mod_u256(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+        unsigned::rem(a, b)
+    }
+
+    pub fn exp_u256(base: U256, exponent: U256) -> Result<U256, Vec<u8>> {
+        unsigned::exp(base, exponent)
+    }
+
+    pub fn shl_u256(a: U256, shift: U256) -> U256 {
+        unsigned::shl(a, shift)
+    }
+
+    pub fn shr_u256(a: U256, shift: U256) -> U256 {
+        unsigned::shr(a, shift)
+    }
+
+    pub fn and_u256(a: U256, b: U256) -> U256 {
+        a & b
+    }
+
+    pub fn or_u256(a: U256, b: U256) -> U256 {
+        a | b
+    }
+
+    pub fn xor_u256(a: U256, b: U256) -> U256 {
+        a ^ b
+    }
+
+    pub fn not_u256(a: U256) -> U256 {
+        !a
+    }
+
+    pub fn lt_u256(a: U256, b: U256) -> bool {
+        a < b
+    }
+
+    pub fn gt_u256(a: U256, b: U256) -> bool {
+        a > b
+    }
+
+    pub fn eq_u256(a: U256, b: U256) -> bool {
+        a == b
+    }
+
+    pub fn ne_u256(a: U256, b: U256) -> bool {
+        a != b
+    }
+
+    pub fn le_u256(a: U256, b: U256) -> bool {
+        a <= b
+    }
+
+    pub fn ge_u256(a: U256, b: U256) -> bool {
+        a >= b
+    }
+
+    pub fn get_mixed(&self) -> (U256, I8, Address, bool) {
+        let signed8 = self.signed8.get();
+        (
+            self.unsigned256.get(),
+            signed8,
+            msg::sender(),
+            signed8.is_negative(),
+        )
+    }
+
+    pub fn get_all(&self) -> AllInts {
+        AllInts {
+            signed8: self.signed8.get().try_into().unwrap(),
+            signed16: self.signed16.get().try_into().unwrap(),
+            signed24: self.signed24.get(),
+            signed32: self.signed32.get().try_into().unwrap(),
+            signed64: self.signed64.get().try_into().unwrap(),
+            signed128: self.signed128.get().try_into().unwrap(),
+            signed160: self.signed160.get(),
+            signed200: self.signed200.get(),
+            signed256: self.signed256.get(),
+            unsigned8: self.unsigned8.get().try_into().unwrap(),
+            unsigned16: self.unsigned16.get().try_into().unwrap(),
+            unsigned24: self.unsigned24.get(),
+            unsigned32: self.unsigned32.get().try_into().unwrap(),
+            unsigned64: self.unsigned64.get().try_into().unwrap(),
+            unsigned128: self.unsigned128.get().try_into().unwrap(),
+            unsigned160: self.unsigned160.get(),
+            unsigned200: self.unsigned200.get(),
+            unsigned256: self.unsigned256.get(),
+        }
+    }
+
+    pub fn set_all(&mut self, values: AllInts) {
+        self.signed8.set(values.signed8.try_into().unwrap());
+        self.signed16.set(values.signed16.try_into().unwrap());
+        self.signed24.set(values.signed24);
+        self.signed32.set(values.signed32.try_into().unwrap());
+        self.signed64.set(values.signed64.try_into().unwrap());
+        self.signed128.set(values.signed128.try_into().unwrap());
+        self.signed160.set(values.signed160);
+        self.signed200.set(values.signed200);
+        self.signed256.set(values.signed256);
+        self.unsigned8.set(values.unsigned8.try_into().unwrap());
+        self.unsigned16.set(values.unsigned16.try_into().unwrap());
+        self.unsigned24.set(values.unsigned24);
+        self.unsigned32.set(values.unsigned32.try_into().unwrap());
+        self.unsigned64.set(values.unsigned64.try_into().unwrap());
+        self.unsigned128.set(values.unsigned128.try_into().unwrap());
+        self.unsigned160.set(values.unsigned160);
+        self.unsigned200.set(values.unsigned200);
+        self.unsigned256.set(values.unsigned256);
+    }
+
+    pub fn get_i8_map(&self, key: I8) -> U256 {
+        self.signed8_map.get(key)
+    }
+
+    pub fn set_i8_map(&mut self, key: I8, value: U256) {
+        self.signed8_map.insert(key, value);
+    }
+
+    pub fn get_i16_map(&self, key: I16) -> I16 {
+        self.signed16_map.get(key)
+    }
+
+    pub fn set_i16_map(&mut self, key: I16, value: I16) {
+        self.signed16_map.insert(key, value);
+    }
+
+    pub fn get_i24_map(&self, key: I24) -> U24 {
+        self.signed24_map.get(key)
+    }
+
+    pub fn set_i24_map(&mut self, key: I24, value: U24) {
+        self.signed24_map.insert(key, value);
+    }
+
+    pub fn get_i32_map(&self, key: I32) -> bool {
+        self.signed32_map.get(key)
+    }
+
+    pub fn set_i32_map(&mut self, key: I32, value: bool) {
+        self.signed32_map.insert(key, value);
+    }
+
+    pub fn get_i64_map(&self, key: I64) -> I64 {
+        self.signed64_map.get(key)
+    }
+
+    pub fn set_i64_map(&mut self, key: I64, value: I64) {
+        self.signed64_map.insert(key, value);
+    }
+
+    pub fn get_i128_map(&self, key: I128) -> U128 {
+        self.signed128_map.get(key)
+    }
+
+    pub fn set_i128_map(&mut self, key: I128, value: U128) {
+        self.signed128_map.insert(key, value);
+    }
+
+    pub fn get_i160_map(&self, key: I160) -> U160 {
+        self.signed160_map.get(key)
+    }
+
+    pub fn set_i160_map(&mut self, key: I160, value: U160) {
+        self.signed160_map.insert(key, value);
+    }
+
+    pub fn get_i200_map(&self, key: I200) -> I8 {
+        self.signed200_map.get(key)
+    }
+
+    pub fn set_i200_map(&mut self, key: I200, value: I8) {
+        self.signed200_map.insert(key, value);
+    }
+
+    pub fn get_i256_map(&self, key: I256) -> bool {
+        self.signed256_map.get(key)
+    }
+
+    pub fn set_i256_map(&mut self, key: I256, value: bool) {
+        self.signed256_map.insert(key, value);
+    }
+
+    pub fn get_u8_map(&self, key: U8) -> I256 {
+        self.unsigned8_map.get(key)
+    }
+
+    pub fn set_u8_map(&mut self, key: U8, value: I256) {
+        self.unsigned8_map.insert(key, value);
+    }
+
+    pub fn get_u16_map(&self, key: U16) -> U16 {
+        self.unsigned16_map.get(key)
+    }
+
+    pub fn set_u16_map(&mut self, key: U16, value: U16) {
+        self.unsigned16_map.insert(key, value);
+    }
+
+    pub fn get_u24_map(&self, key: U24) -> I200 {
+        self.unsigned24_map.get(key)
+    }
+
+    pub fn set_u24_map(&mut self, key: U24, value: I200) {
+        self.unsigned24_map.insert(key, value);
+    }
+
+    pub fn get_u32_map(&self, key: U32) -> bool {
+        self.unsigned32_map.get(key)
+    }
+
+    pub fn set_u32_map(&mut self, key: U32, value: bool) {
+        self.unsigned32_map.insert(key, value);
+    }
+
+    pub fn get_u64_map(&self, key: U64) -> U64 {
+        self.unsigned64_map.get(key)
+    }
+
+    pub fn set_u64_map(&mut self, key: U64, value: U64) {
+        self.unsigned64_map.insert(key, value);
+    }
+
+    pub fn get_u128_map(&self, key: U128) -> I128 {
+        self.unsigned128_map.get(key)
+    }
+
+    pub fn set_u128_map(&mut self, key: U128, value: I128) {
+        self.unsigned128_map.insert(key, value);
+    }
+
+    pub fn get_u160_map(&self, key: U160) -> U8 {
+        self.unsigned160_map.get(key)
+    }
+
+    pub fn set_u160_map(&mut self, key: U160, value: U8) {
+        self.unsigned160_map.insert(key, value);
+    }
+
+    pub fn get_u200_map(&self, key: U200) -> U256 {
+        self.unsigned200_map.get(key)
+    }
+
+    pub fn set_u200_map(&mut self, key: U200, value: U256) {
+        self.unsigned200_map.insert(key, value);
+    }
+
+    pub fn get_u256_map(&self, key: U256) -> I24 {
+        self.unsigned256_map.get(key)
+    }
+
+    pub fn set_u256_map(&mut self, key: U256, value: I24) {
+        self.unsigned256_map.insert(key, value);
+    }
+
+    pub fn echo_i8_array(values: Vec<I8>) -> Vec<I8> {
+        values
+    }
+
+    pub fn echo_u24_fixed_array(values: [U24; 4]) -> [U24; 4] {
+        values
+    }
+
+    pub fn echo_i256_nested_array(values: Vec<Vec<I256>>) -> Vec<Vec<I256>> {
+        values
+    }
+
+    pub fn echo_u160_array(values: Vec<U160>) -> Vec<U160> {
+        values
+    }
+
+    pub fn len_i8_vec(&self) -> U256 {
+        U256::from(self.signed8_vec.len())
+    }
+
+    pub fn push_i8_vec(&mut self, value: I8) {
+        self.signed8_vec.push(value);
+    }
+
+    pub fn pop_i8_vec(&mut self) -> Result<I8, Vec<u8>> {
+        self.signed8_vec.pop().ok_or_else(empty_array_pop)
+    }
+
+    pub fn get_i8_vec(&self, index: U256) -> Result<I8, Vec<u8>> {
+        let index = usize::try_from(index).map_err(|_| array_out_of_bounds())?;
+        self.signed8_vec.get(index).ok_or_else(array_out_of_bounds)
+    }
+
+    pub fn slice_i8_vec(&self, offset: U256, len: U256) -> Result<Vec<I8>, Vec<u8>> {
+        let range = slice_range(offset, len, self.signed8_vec.len())?;
+        Ok(range.map(|i| self.signed8_vec.get(i).unwrap()).collect())
+    }
+
+    pub fn len_u24_vec(&self) -> U256 {
+        U256::from(self.unsigned24_vec.len())
+    }
+
+    pub fn push_u24_vec(&mut self, value: U24) {
+        self.unsigned24_vec.push(value);
+    }
+
+    pub fn pop_u24_vec(&mut self) -> Result<U24, Vec<u8>> {
+        self.unsigned24_vec.pop().ok_or_else(empty_array_pop)
+    }
+
+    pub fn get_u24_vec(&self, index: U256) -> Result<U24, Vec<u8>> {
+        let index = usize::try_from(index).map_err(|_| array_out_of_bounds())?;
+        self.unsigned24_vec
+            .get(index)
+            .ok_or_else(array_out_of_bounds)
+    }
+
+    pub fn slice_u24_vec(&self, offset: U256, len: U256) -> Result<Vec<U24>, Vec<u8>> {
+        let range = slice_range(offset, len, self.unsigned24_vec.len())?;
+        Ok(range.map(|i| self.unsigned24_vec.get(i).unwrap()).collect())
+    }
+
+    pub fn len_u160_vec(&self) -> U256 {
+        U256::from(self.unsigned160_vec.len())
+    }
+
+    pub fn push_u160_vec(&mut self, value: U160) {
+        self.unsigned160_vec.push(value);
+    }
+
+    pub fn pop_u160_vec(&mut self) -> Result<U160, Vec<u8>> {
+        self.unsigned160_vec.pop().ok_or_else(empty_array_pop)
+    }
+
+    pub fn get_u160_vec(&self, index: U256) -> Result<U160, Vec<u8>> {
+        let index = usize::try_from(index).map_err(|_| array_out_of_bounds())?;
+        self.unsigned160_vec
+            .get(index)
+            .ok_or_else(array_out_of_bounds)
+    }
+
+    pub fn slice_u160_vec(&self, offset: U256, len: U256) -> Result<Vec<U160>, Vec<u8>> {
+        let range = slice_range(offset, len, self.unsigned160_vec.len())?;
+        Ok(range
+            .map(|i| self.unsigned160_vec.get(i).unwrap())
+            .collect())
+    }
+
+    pub fn len_i256_vec(&self) -> U256 {
+        U256::from(self.signed256_vec.len())
+    }
+
+    pub fn push_i256_vec(&mut self, value: I256) {
+        self.signed256_vec.push(value);
+    }
+
+    pub fn pop_i256_vec(&mut self) -> Result<I256, Vec<u8>> {
+        self.signed256_vec.pop().ok_or_else(empty_array_pop)
+    }
+
+    pub fn get_i256_vec(&self, index: U256) -> Result<I256, Vec<u8>> {
+        let index = usize::try_from(index).map_err(|_| array_out_of_bounds())?;
+        self.signed256_vec
+            .get(index)
+            .ok_or_else(array_out_of_bounds)
+    }
+
+    pub fn slice_i256_vec(&self, offset: U256, len: U256) -> Result<Vec<I256>, Vec<u8>> {
+        let range = slice_range(offset, len, self.signed256_vec.len())?;
+        Ok(range.map(|i| self.signed256_vec.get(i).unwrap()).collect())
+    }
+
+    pub fn raw_slot(&self, slot: U256) -> B256 {
+        // Views never have pending writes, so the slot can be read without flushing the cache.
+        let key = B256::from(slot);
+        let mut value = B256::ZERO;
+        unsafe { hostio::storage_load_bytes32(key.as_ptr(), value.as_mut_ptr()) };
+        value
     }
 }
@@ -2,5 +2,9 @@
 
 #[cfg(feature = "export-abi")]
 fn main() {
+    if std::env::args().any(|arg| arg == "--storage-layout") {
+        println!("{}", stylus_hello_world::Counter::layout().to_json());
+        return;
+    }
     stylus_hello_world::print_abi("MIT-OR-APACHE-2.0", "pragma solidity ^0.8.23;");
 }
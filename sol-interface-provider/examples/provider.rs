@@ -0,0 +1,110 @@
+//! Example on how to interact with a deployed `sol-interface-provider` program.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It sets `myNum` via a tx and checks `doThing` returns it as a `MyStruct`, then switches
+//! through the modes that make `doThing` revert or return empty and truncated data.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes, TransactionRequest},
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        MyInterfaceProvider,
+        r#"[
+            struct MyStruct { uint256 myNum; }
+            function doThing() external view returns (MyStruct)
+            function setMyNum(uint256 myNum) external
+            function setMode(uint8 mode) external
+            error DoThingFailed(uint256 myNum)
+            error UnknownMode(uint8 mode)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let my_interface = MyInterfaceProvider::new(address, client.clone());
+    my_interface.set_mode(0).send().await?.await?;
+
+    let pending = my_interface.set_my_num(U256::from(7));
+    if let Some(receipt) = pending.send().await?.await? {
+        println!("Receipt = {:?}", receipt);
+    }
+    println!("Successfully set myNum via a tx");
+
+    let result = my_interface.do_thing().call().await?;
+    println!("doThing returned {:?}", result);
+    assert_eq!(U256::from(7), result.my_num);
+
+    // Mode 1 reverts with the custom error.
+    my_interface.set_mode(1).send().await?.await?;
+    let err = my_interface
+        .do_thing()
+        .call()
+        .await
+        .expect_err("doThing should revert");
+    match err.decode_contract_revert::<MyInterfaceProviderErrors>() {
+        Some(MyInterfaceProviderErrors::DoThingFailed(DoThingFailed { my_num })) => {
+            assert_eq!(U256::from(7), my_num)
+        }
+        other => return Err(eyre!("expected DoThingFailed, got {other:?}")),
+    }
+    println!("doThing reverts with DoThingFailed");
+
+    // Modes 2 and 3 succeed with 0 and 31 bytes of output.
+    let call = TransactionRequest::new()
+        .to(address)
+        .data(my_interface.do_thing().calldata().unwrap());
+    for (mode, len) in [(2, 0), (3, 31)] {
+        my_interface.set_mode(mode).send().await?.await?;
+        let output: Bytes = client.call(&call.clone().into(), None).await?;
+        assert_eq!(len, output.len());
+        assert!(my_interface.do_thing().call().await.is_err());
+        println!("doThing returns {len} bytes in mode {mode}");
+    }
+
+    assert!(my_interface.set_mode(7).call().await.is_err());
+    my_interface.set_mode(0).send().await?.await?;
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
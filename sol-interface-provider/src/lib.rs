@@ -0,0 +1,192 @@
+//!
+//! Stylus Hello World
+//!
+//! The following contract implements the `IMyInterface` declared in `sol-interface-struct`, so
+//! that its `Counter` can be pointed at a real callee.
+//!
+//! ```
+//! struct MyStruct {
+//!     uint256 myNum;
+//! }
+//!
+//! contract Provider {
+//!     uint256 myNum;
+//!     uint8 mode;
+//!     function doThing() public view returns (MyStruct memory) {
+//!         if (mode == 1) revert DoThingFailed(myNum);
+//!         return MyStruct(myNum);
+//!     }
+//!     function setMyNum(uint256 newMyNum) public {
+//!         myNum = newMyNum;
+//!     }
+//!     function setMode(uint8 newMode) public {
+//!         mode = newMode;
+//!     }
+//!     function echoThing(MyStruct memory thing) public pure returns (MyStruct memory) {
+//!         return thing;
+//!     }
+//!     function sumThings(MyStruct[] memory things) public pure returns (uint256 sum) {
+//!         for (uint256 i = 0; i < things.length; i++) sum += things[i].myNum;
+//!     }
+//!     function depositThing(MyStruct memory thing) public payable returns (uint256) {
+//!         myNum = thing.myNum;
+//!         return msg.value;
+//!     }
+//! }
+//! ```
+//!
+//! Modes 2 and 3 make `doThing` succeed with empty or truncated return data, which can't be
+//! written in Solidity, so the callers' handling of malformed output can be tested too. Modes 4
+//! and 5 make it revert with `Error(string)` and `Panic(uint256)` instead of the custom error,
+//! and in mode 6 both `doThing` and `depositThing` run out of gas.
+//!
+//! The program is ABI-equivalent with Solidity, which means you can call it from both Solidity and Rust.
+//! To do this, run `cargo stylus export-abi`.
+//!
+//! Note: this code is a template-only and has not been audited.
+//!
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+/// Use an efficient WASM allocator.
+#[global_allocator]
+static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, Panic, PanicKind, Revert, SolCall, SolError, SolValue};
+/// Import items from the SDK. The prelude contains common traits and macros.
+use stylus_sdk::{
+    abi::router_entrypoint,
+    alloy_primitives::{U256, U8},
+    evm, msg,
+    prelude::*,
+};
+
+/// `doThing` reverts with `DoThingFailed`. In the default mode 0 it returns the configured struct.
+const MODE_REVERT: u8 = 1;
+/// `doThing` succeeds without return data.
+const MODE_EMPTY: u8 = 2;
+/// `doThing` succeeds with the encoded struct cut one byte short.
+const MODE_SHORT: u8 = 3;
+/// `doThing` reverts with `Error(string)`.
+const MODE_REVERT_STRING: u8 = 4;
+/// `doThing` reverts with `Panic(uint256)`.
+const MODE_PANIC: u8 = 5;
+/// `doThing` and `depositThing` spin until they run out of gas.
+const MODE_BURN_GAS: u8 = 6;
+
+// Define some persistent storage using the Solidity ABI.
+// `user_main` below is the entrypoint, routing to `Provider`.
+sol_storage! {
+    pub struct Provider {
+        uint256 my_num;
+        uint8 mode;
+    }
+}
+
+/// `Provider` is the only storage of the program, as `#[entrypoint]` on the struct would declare.
+unsafe impl TopLevelStorage for Provider {}
+
+sol! {
+    #[derive(AbiType)]
+    struct MyStruct {
+        uint256 myNum;
+    }
+
+    function doThing() returns (MyStruct);
+
+    error DoThingFailed(uint256 myNum);
+    error UnknownMode(uint8 mode);
+}
+
+/// Answers `doThing` with malformed data in the modes that ask for it, since an `#[external]`
+/// method can only return well-formed values. Everything else goes to the `Provider` router.
+#[entrypoint]
+fn user_main(input: Vec<u8>) -> ArbResult {
+    if input.starts_with(&doThingCall::SELECTOR) {
+        // Safety: the program has no other storage, so `Provider` is rooted at slot 0.
+        let provider = unsafe { Provider::new(U256::ZERO, 0) };
+        let output = MyStruct {
+            myNum: provider.my_num.get(),
+        }
+        .abi_encode();
+        match provider.mode.get().to::<u8>() {
+            MODE_EMPTY => return Ok(Vec::new()),
+            MODE_SHORT => return Ok(output[..output.len() - 1].to_vec()),
+            _ => {}
+        }
+    }
+    router_entrypoint::<Provider, Provider>(input)
+}
+
+/// Uses up all the gas the call was given.
+fn burn_gas() -> ! {
+    loop {
+        evm::gas_left();
+    }
+}
+
+/// Prints the Solidity ABI, which `#[entrypoint]` on a function doesn't generate.
+#[cfg(feature = "export-abi")]
+pub fn print_abi(license: &str, pragma: &str) {
+    stylus_sdk::abi::export::print_abi::<Provider>(license, pragma);
+}
+
+/// Declare that `Provider` is a contract with the following external methods.
+#[external]
+impl Provider {
+    /// Returns the configured struct, as `IMyInterface.doThing` does, or reverts with
+    /// `DoThingFailed`, `Error(string)` or `Panic(uint256)` in the reverting modes.
+    pub fn do_thing(&self) -> Result<MyStruct, Vec<u8>> {
+        let my_num = self.my_num.get();
+        match self.mode.get().to::<u8>() {
+            MODE_REVERT => Err(DoThingFailed { myNum: my_num }.abi_encode()),
+            MODE_REVERT_STRING => Err(Revert {
+                reason: "doThing failed".into(),
+            }
+            .abi_encode()),
+            MODE_PANIC => Err(Panic::from(PanicKind::Assert).abi_encode()),
+            MODE_BURN_GAS => burn_gas(),
+            _ => Ok(MyStruct { myNum: my_num }),
+        }
+    }
+
+    /// Sets how `do_thing` responds.
+    pub fn set_mode(&mut self, mode: u8) -> Result<(), Vec<u8>> {
+        if mode > MODE_BURN_GAS {
+            return Err(UnknownMode { mode }.abi_encode());
+        }
+        self.mode.set(U8::from(mode));
+        Ok(())
+    }
+
+    /// Sets the `myNum` that `do_thing` returns.
+    pub fn set_my_num(&mut self, my_num: U256) {
+        self.my_num.set(my_num);
+    }
+
+    /// Returns its struct argument.
+    pub fn echo_thing(thing: MyStruct) -> MyStruct {
+        thing
+    }
+
+    /// Adds up the `myNum` of every struct, with Solidity's overflow check.
+    pub fn sum_things(things: Vec<MyStruct>) -> Result<U256, Vec<u8>> {
+        things.iter().try_fold(U256::ZERO, |sum, thing| {
+            sum.checked_add(thing.myNum)
+                .ok_or_else(|| Panic::from(PanicKind::UnderOverflow).abi_encode())
+        })
+    }
+
+    /// Stores the struct's `myNum` and returns the value sent with the call.
+    #[payable]
+    pub fn deposit_thing(&mut self, thing: MyStruct) -> U256 {
+        if self.mode.get().to::<u8>() == MODE_BURN_GAS {
+            burn_gas();
+        }
+        self.my_num.set(thing.myNum);
+        msg::value()
+    }
+}
@@ -0,0 +1,93 @@
+//!
+//! Stylus Hello World
+//!
+//! The following contract implements the `IMyInterface.doThing` of `sol-interface-struct`
+//! maliciously: it re-enters the calling `Counter` to overwrite its number.
+//!
+//! ```
+//! struct MyStruct {
+//!     uint256 myNum;
+//! }
+//!
+//! contract MaliciousProvider {
+//!     uint256 newNumber;
+//!     uint256 observed;
+//!     function doThing() public returns (MyStruct memory) {
+//!         ICounter counter = ICounter(msg.sender);
+//!         observed = counter.number();
+//!         counter.setNumber(newNumber);
+//!         return MyStruct(observed);
+//!     }
+//!     function setNewNumber(uint256 number) public {
+//!         newNumber = number;
+//!     }
+//! }
+//! ```
+//!
+//! The program is ABI-equivalent with Solidity, which means you can call it from both Solidity and Rust.
+//! To do this, run `cargo stylus export-abi`.
+//!
+//! Note: this code is a template-only and has not been audited.
+//!
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+/// Use an efficient WASM allocator.
+#[global_allocator]
+static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
+
+use alloc::vec::Vec;
+use alloy_sol_types::sol;
+/// Import items from the SDK. The prelude contains common traits and macros.
+use stylus_sdk::{alloy_primitives::U256, call::Call, msg, prelude::*};
+
+// Define some persistent storage using the Solidity ABI.
+// `MaliciousProvider` will be the entrypoint.
+sol_storage! {
+    #[entrypoint]
+    pub struct MaliciousProvider {
+        uint256 new_number;
+        uint256 observed;
+    }
+}
+
+sol! {
+    #[derive(AbiType)]
+    struct MyStruct {
+        uint256 myNum;
+    }
+}
+
+sol_interface! {
+    interface ICounter {
+        function number() view returns (uint256);
+        function setNumber(uint256 newNumber);
+    }
+}
+
+/// Declare that `MaliciousProvider` is a contract with the following external methods.
+#[external]
+impl MaliciousProvider {
+    /// Reads the number of the calling counter, then sets it to `new_number` and returns the
+    /// number it read. A revert of the counter, such as its reentrancy guard, is bubbled up.
+    pub fn do_thing(&mut self) -> Result<MyStruct, Vec<u8>> {
+        let counter = ICounter::new(msg::sender());
+        let observed = counter.number(Call::new_in(self))?;
+        let new_number = self.new_number.get();
+        counter.set_number(Call::new_in(self), new_number)?;
+        self.observed.set(observed);
+        Ok(MyStruct { myNum: observed })
+    }
+
+    /// Sets the number `do_thing` writes into the caller.
+    pub fn set_new_number(&mut self, number: U256) {
+        self.new_number.set(number);
+    }
+
+    /// Gets the number of the caller as `do_thing` last saw it.
+    pub fn observed(&self) -> U256 {
+        self.observed.get()
+    }
+}
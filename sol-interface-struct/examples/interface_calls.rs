@@ -0,0 +1,128 @@
+//! Example on how a deployed `sol-interface-struct` program passes structs through `IMyInterface`.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! Against a deployed `sol-interface-provider` program, it round trips a `MyStruct` argument and
+//! sums a `MyStruct[]` through the view methods, which the counter calls statically, then sends
+//! value through the payable `depositThing` and checks it reached the provider.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Deployed `sol-interface-provider` program address.
+const PROVIDER_PROGRAM_ADDRESS: &str = "PROVIDER_ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    let provider_address = std::env::var(PROVIDER_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", PROVIDER_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct MyStruct { uint256 myNum; }
+            function number() external view returns (uint256)
+            function callEchoThing(address target, MyStruct thing) external view returns (MyStruct)
+            function callSumThings(address target, MyStruct[] things) external view returns (uint256)
+            function callDepositThing(address target, MyStruct thing) external payable returns (uint256)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let target: Address = provider_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client.clone());
+
+    // MyStruct as a view argument
+    let thing = MyStruct {
+        my_num: U256::from(3),
+    };
+    assert_eq!(
+        thing,
+        counter
+            .call_echo_thing(target, thing.clone())
+            .call()
+            .await?
+    );
+    println!("Successfully echoed a MyStruct through the provider");
+
+    // MyStruct[] as a view argument, including the overflow check on the provider's side
+    let things: Vec<MyStruct> = (1..=4u64)
+        .map(|n| MyStruct {
+            my_num: U256::from(n),
+        })
+        .collect();
+    assert_eq!(
+        U256::from(10),
+        counter.call_sum_things(target, things).call().await?
+    );
+    assert_eq!(
+        U256::zero(),
+        counter.call_sum_things(target, vec![]).call().await?
+    );
+    let overflowing = vec![
+        MyStruct { my_num: U256::MAX },
+        MyStruct {
+            my_num: U256::one(),
+        },
+    ];
+    assert!(counter
+        .call_sum_things(target, overflowing)
+        .call()
+        .await
+        .is_err());
+    println!("Successfully summed a MyStruct[] through the provider");
+
+    // Payable call carrying the value sent to the counter
+    let value = U256::from(1_000);
+    let balance = client.get_balance(target, None).await?;
+    counter
+        .call_deposit_thing(target, thing.clone())
+        .value(value)
+        .send()
+        .await?
+        .await?;
+    assert_eq!(balance + value, client.get_balance(target, None).await?);
+    assert_eq!(value, counter.number().call().await?);
+    println!("Successfully forwarded {value} wei to the provider");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
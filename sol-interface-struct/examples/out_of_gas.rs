@@ -0,0 +1,140 @@
+//! Example on how `sol-interface-struct` tells a callee running out of gas from other failures.
+//! This example runs offline: it feeds the revert data and gas figures of a capped call to the
+//! same `CalleeError` decoding the program uses, and checks the errors it re-raises. An empty
+//! revert that used up all the gas forwarded becomes `CalleeOutOfGas`, while one that left gas
+//! over stays a generic `CalleeFailed`, and revert data the callee did return always takes
+//! precedence. The call overhead the caller pays is told apart from the gas the callee used, so
+//! this holds for caps below the overhead, and for caps above the gas left to forward. Either
+//! way the callee may leave up to `OUT_OF_GAS_MARGIN` unused and still count as out of gas, but
+//! not a gas more.
+
+use alloy_sol_types::{Panic, PanicKind, Revert, SolError};
+use stylus_hello_world::revert::{
+    call_overhead, CalleeDoThingFailed, CalleeError, CalleeFailed, CalleeOutOfGas, CalleePanicked,
+    CalleeReverted, DoThingFailed, OUT_OF_GAS_MARGIN,
+};
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+/// The gas cap of the calls below, unless they say otherwise.
+const GAS: u64 = 100_000;
+
+/// The gas left before each call, unless it says otherwise.
+const GAS_BEFORE: u64 = 1_000_000;
+
+/// Gas the program spends around the call besides the call itself.
+const SLACK: u64 = 50;
+
+/// Checks what an empty revert of a call capped at `gas` is re-raised as. `Some(forwarded)` is
+/// `CalleeOutOfGas` with the gas forwarded, and `None` is `CalleeFailed`.
+fn check_empty_revert(
+    target: Address,
+    gas: u64,
+    overhead: u64,
+    gas_before: u64,
+    gas_after: u64,
+    expected: Option<u64>,
+) -> eyre::Result<()> {
+    let data =
+        CalleeError::decode_capped(&[], gas, overhead, gas_before, gas_after).reraise(target);
+    match expected {
+        Some(forwarded) => {
+            let err = CalleeOutOfGas::abi_decode(&data, true)?;
+            assert_eq!((target, forwarded), (err.target, err.gas));
+        }
+        None => {
+            assert!(CalleeOutOfGas::abi_decode(&data, true).is_err());
+            let err = CalleeFailed::abi_decode(&data, true)?;
+            assert_eq!(target, err.target);
+            assert!(err.revertData.is_empty());
+        }
+    }
+    Ok(())
+}
+
+fn main() -> eyre::Result<()> {
+    let target = Address::repeat_byte(0x42);
+    let overhead = call_overhead(U256::ZERO);
+
+    // Out of gas: nothing returned, and the whole cap plus the call overhead is gone.
+    let gas_after = GAS_BEFORE - overhead - GAS - SLACK;
+    check_empty_revert(target, GAS, overhead, GAS_BEFORE, gas_after, Some(GAS))?;
+    println!("Empty revert using the whole cap is CalleeOutOfGas");
+
+    // An empty revert that left gas over is a plain failure.
+    let gas_after = GAS_BEFORE - overhead - 21_000 - SLACK;
+    check_empty_revert(target, GAS, overhead, GAS_BEFORE, gas_after, None)?;
+    println!("Empty revert leaving gas over is CalleeFailed");
+
+    // The overhead estimate is allowed to be off by up to the margin, but not by more.
+    let gas_after = GAS_BEFORE - overhead - GAS + OUT_OF_GAS_MARGIN;
+    check_empty_revert(target, GAS, overhead, GAS_BEFORE, gas_after, Some(GAS))?;
+    check_empty_revert(target, GAS, overhead, GAS_BEFORE, gas_after + 1, None)?;
+    println!("Empty revert leaving up to {OUT_OF_GAS_MARGIN} gas of the cap is CalleeOutOfGas");
+
+    // A cap below the overhead of a call carrying value: the caller's gas drops by more than
+    // the cap even when the callee used little of it.
+    let gas = 1_000;
+    let overhead = call_overhead(U256::from(1));
+    assert!(gas < overhead);
+    let gas_after = GAS_BEFORE - overhead - 200 - SLACK;
+    check_empty_revert(target, gas, overhead, GAS_BEFORE, gas_after, None)?;
+    let gas_after = GAS_BEFORE - overhead - gas - SLACK;
+    check_empty_revert(target, gas, overhead, GAS_BEFORE, gas_after, Some(gas))?;
+    println!("A cap below the call overhead is only CalleeOutOfGas once used up");
+
+    // A cap above the gas left: the callee gets all but a 64th of it, and using that up leaves
+    // the caller's gas dropping by less than the cap.
+    let overhead = call_overhead(U256::ZERO);
+    let gas_before = 64_000;
+    let available = gas_before - overhead;
+    let forwarded = available - available / 64;
+    assert!(GAS > forwarded);
+    let gas_after = gas_before - overhead - forwarded - SLACK.min(available / 64);
+    check_empty_revert(
+        target,
+        GAS,
+        overhead,
+        gas_before,
+        gas_after,
+        Some(forwarded),
+    )?;
+    let gas_after = available / 64 + OUT_OF_GAS_MARGIN;
+    check_empty_revert(
+        target,
+        GAS,
+        overhead,
+        gas_before,
+        gas_after,
+        Some(forwarded),
+    )?;
+    check_empty_revert(target, GAS, overhead, gas_before, gas_after + 1, None)?;
+    let gas_after = gas_before - overhead - 21_000 - SLACK;
+    check_empty_revert(target, GAS, overhead, gas_before, gas_after, None)?;
+    println!("A cap above the gas left is CalleeOutOfGas once the 63/64 are used up");
+
+    // Revert data wins over the gas used, even with all of it gone.
+    let revert = DoThingFailed {
+        myNum: U256::from(5),
+    }
+    .abi_encode();
+    let data = CalleeError::decode_capped(&revert, GAS, overhead, GAS_BEFORE, 0).reraise(target);
+    let err = CalleeDoThingFailed::abi_decode(&data, true)?;
+    assert_eq!((target, U256::from(5)), (err.target, err.myNum));
+
+    let revert = Revert {
+        reason: "doThing failed".into(),
+    }
+    .abi_encode();
+    let data = CalleeError::decode_capped(&revert, GAS, overhead, GAS_BEFORE, 0).reraise(target);
+    assert_eq!(
+        "doThing failed",
+        CalleeReverted::abi_decode(&data, true)?.reason
+    );
+
+    let revert = Panic::from(PanicKind::Assert).abi_encode();
+    let data = CalleeError::decode_capped(&revert, GAS, overhead, GAS_BEFORE, 0).reraise(target);
+    assert_eq!(U256::from(1), CalleePanicked::abi_decode(&data, true)?.code);
+    println!("Revert data is decoded whatever gas was used");
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Example on how a deployed `sol-interface-struct` program handles a failing `IMyInterface.doThing`.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It switches a deployed `sol-interface-provider` program between its modes and checks that
+//! `callDoThing` bubbles the provider's custom error unchanged, and reverts rather than storing a
+//! number when the provider returns empty or truncated data.
+
+use ethers::{
+    abi::{encode, Token},
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    utils::id,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Deployed `sol-interface-provider` program address.
+const PROVIDER_PROGRAM_ADDRESS: &str = "PROVIDER_ADDR";
+
+/// The provider lives in its own module so its `MyStruct` doesn't clash with the counter's.
+mod my_interface {
+    ethers::prelude::abigen!(
+        MyInterfaceProvider,
+        r#"[
+            struct MyStruct { uint256 myNum; }
+            function doThing() external view returns (MyStruct)
+            function setMyNum(uint256 myNum) external
+            function setMode(uint8 mode) external
+        ]"#
+    );
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    let provider_address = std::env::var(PROVIDER_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", PROVIDER_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct MyStruct { uint256 myNum; }
+            function number() external view returns (uint256)
+            function callDoThing(address target) external returns (MyStruct)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let target: Address = provider_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client.clone());
+    let my_interface = my_interface::MyInterfaceProvider::new(target, client);
+
+    my_interface
+        .set_my_num(U256::from(99))
+        .send()
+        .await?
+        .await?;
+    my_interface.set_mode(0).send().await?.await?;
+    counter.call_do_thing(target).send().await?.await?;
+    assert_eq!(U256::from(99), counter.number().call().await?);
+
+    // The provider's revert data comes back from the counter byte for byte.
+    my_interface.set_my_num(U256::from(5)).send().await?.await?;
+    my_interface.set_mode(1).send().await?.await?;
+    let err = counter
+        .call_do_thing(target)
+        .call()
+        .await
+        .expect_err("callDoThing should revert");
+    let data = err
+        .as_revert()
+        .ok_or_else(|| eyre!("callDoThing failed without revert data: {err}"))?;
+    let mut expected = id("DoThingFailed(uint256)").to_vec();
+    expected.extend(encode(&[Token::Uint(U256::from(5))]));
+    assert_eq!(expected, data.to_vec());
+    println!("callDoThing bubbles DoThingFailed");
+
+    // Return data that doesn't decode as a MyStruct fails the call instead of storing garbage.
+    for mode in [2, 3] {
+        my_interface.set_mode(mode).send().await?.await?;
+        let err = counter
+            .call_do_thing(target)
+            .call()
+            .await
+            .expect_err("callDoThing should revert");
+        let data = err
+            .as_revert()
+            .ok_or_else(|| eyre!("callDoThing failed without revert data: {err}"))?;
+        assert!(!data.starts_with(&expected[..4]));
+        println!("callDoThing rejects malformed data in mode {mode}: {data}");
+    }
+    assert_eq!(U256::from(99), counter.number().call().await?);
+
+    my_interface.set_mode(0).send().await?.await?;
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,113 @@
+//! Example on how a deployed `sol-interface-struct` program behaves when a callee re-enters it.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! With a deployed `sol-interface-reentrant` program as the callee, it checks the increment made
+//! before the call is flushed to storage where the callee reads it, that the counter reloads the
+//! number the callee writes back, and that the guarded variant reverts with `Reentrancy`.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    utils::id,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Deployed `sol-interface-reentrant` program address.
+const REENTRANT_PROGRAM_ADDRESS: &str = "REENTRANT_ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    let reentrant_address = std::env::var(REENTRANT_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", REENTRANT_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            function number() external view returns (uint256)
+            function setNumber(uint256 number) external
+            function incrementAndCall(address target) external returns (uint256)
+            function guardedIncrementAndCall(address target) external returns (uint256)
+        ]"#
+    );
+    abigen!(
+        MaliciousProvider,
+        r#"[
+            function setNewNumber(uint256 number) external
+            function observed() external view returns (uint256)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let target: Address = reentrant_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client.clone());
+    let malicious = MaliciousProvider::new(target, client);
+
+    counter.set_number(U256::from(10)).send().await?.await?;
+    malicious
+        .set_new_number(U256::from(777))
+        .send()
+        .await?
+        .await?;
+
+    // Unguarded: the callee overwrites the number and the counter sees it.
+    assert_eq!(
+        U256::from(777),
+        counter.increment_and_call(target).call().await?
+    );
+    counter.increment_and_call(target).send().await?.await?;
+    assert_eq!(U256::from(11), malicious.observed().call().await?);
+    assert_eq!(U256::from(777), counter.number().call().await?);
+    println!("The callee read the flushed increment and the counter reloaded its write");
+
+    // Guarded: the reentrant setNumber reverts, and so does the whole call.
+    let err = counter
+        .guarded_increment_and_call(target)
+        .call()
+        .await
+        .expect_err("guardedIncrementAndCall should revert");
+    let data = err
+        .as_revert()
+        .ok_or_else(|| eyre!("guardedIncrementAndCall failed without revert data: {err}"))?;
+    assert_eq!(id("Reentrancy()").to_vec(), data.to_vec());
+    assert_eq!(U256::from(777), counter.number().call().await?);
+    println!("The guarded variant reverts with Reentrancy");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
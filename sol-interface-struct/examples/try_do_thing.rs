@@ -0,0 +1,151 @@
+//! Example on how a deployed `sol-interface-struct` program reports and types callee failures.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It switches a deployed `sol-interface-provider` program through its modes and checks what
+//! `tryDoThing` reports for each, including the marker for malformed return data, then checks
+//! `callDoThingTyped` re-raises the custom error, `Error(string)` and `Panic(uint256)` of the
+//! provider as the counter's own errors.
+
+use ethers::{
+    abi::{encode, Token},
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    utils::id,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Deployed `sol-interface-provider` program address.
+const PROVIDER_PROGRAM_ADDRESS: &str = "PROVIDER_ADDR";
+
+/// The provider lives in its own module so its `MyStruct` doesn't clash with the counter's.
+mod my_interface {
+    ethers::prelude::abigen!(
+        MyInterfaceProvider,
+        r#"[
+            struct MyStruct { uint256 myNum; }
+            function doThing() external view returns (MyStruct)
+            function setMyNum(uint256 myNum) external
+            function setMode(uint8 mode) external
+        ]"#
+    );
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    let provider_address = std::env::var(PROVIDER_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", PROVIDER_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct MyStruct { uint256 myNum; }
+            error CalleeDoThingFailed(address target, uint256 myNum)
+            error CalleeReverted(address target, string reason)
+            error CalleePanicked(address target, uint256 code)
+            error CalleeFailed(address target, bytes revertData)
+            function tryDoThing(address target) external returns (bool, MyStruct, bytes)
+            function callDoThingTyped(address target) external returns (MyStruct)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+    let target: Address = provider_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client.clone());
+    let my_interface = my_interface::MyInterfaceProvider::new(target, client);
+
+    my_interface.set_my_num(U256::from(5)).send().await?.await?;
+
+    // tryDoThing reports rather than reverts.
+    my_interface.set_mode(0).send().await?.await?;
+    let (ok, result, revert_data) = counter.try_do_thing(target).call().await?;
+    assert!(ok && revert_data.is_empty());
+    assert_eq!(U256::from(5), result.my_num);
+
+    my_interface.set_mode(1).send().await?.await?;
+    let (ok, result, revert_data) = counter.try_do_thing(target).call().await?;
+    let mut expected = id("DoThingFailed(uint256)").to_vec();
+    expected.extend(encode(&[Token::Uint(U256::from(5))]));
+    assert!(!ok && result.my_num.is_zero());
+    assert_eq!(expected, revert_data.to_vec());
+
+    // Malformed return data is marked, unlike an empty revert such as running out of gas.
+    let mut malformed = id("CalleeReturnedMalformedData(address)").to_vec();
+    malformed.extend(encode(&[Token::Address(target)]));
+    for mode in [2, 3] {
+        my_interface.set_mode(mode).send().await?.await?;
+        let (ok, _, revert_data) = counter.try_do_thing(target).call().await?;
+        assert!(!ok, "mode {mode}");
+        assert_eq!(malformed, revert_data.to_vec(), "mode {mode}");
+    }
+    my_interface.set_mode(6).send().await?.await?;
+    let (ok, _, revert_data) = counter.try_do_thing(target).call().await?;
+    assert!(!ok && revert_data.is_empty());
+    println!("tryDoThing reports every failure mode");
+
+    // callDoThingTyped re-raises each kind of revert as the counter's own error.
+    for mode in [1, 4, 5] {
+        my_interface.set_mode(mode).send().await?.await?;
+        let err = counter
+            .call_do_thing_typed(target)
+            .call()
+            .await
+            .expect_err("callDoThingTyped should revert");
+        match (mode, err.decode_contract_revert::<CounterErrors>()) {
+            (1, Some(CounterErrors::CalleeDoThingFailed(err))) => {
+                assert_eq!((target, U256::from(5)), (err.target, err.my_num))
+            }
+            (4, Some(CounterErrors::CalleeReverted(err))) => {
+                assert_eq!(
+                    (target, "doThing failed"),
+                    (err.target, err.reason.as_str())
+                )
+            }
+            (5, Some(CounterErrors::CalleePanicked(err))) => {
+                assert_eq!((target, U256::one()), (err.target, err.code))
+            }
+            (mode, other) => return Err(eyre!("mode {mode} re-raised {other:?}")),
+        }
+        println!("callDoThingTyped re-raises mode {mode} as a typed error");
+    }
+
+    my_interface.set_mode(0).send().await?.await?;
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
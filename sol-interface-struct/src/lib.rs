@@ -29,9 +29,19 @@ extern crate alloc;
 #[global_allocator]
 static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
 
-use alloy_sol_types::sol;
+pub mod revert;
+
+use crate::revert::{call_overhead, CalleeError, CalleeReturnedMalformedData};
+use alloy_sol_types::{sol, Panic, PanicKind, SolError};
 /// Import items from the SDK. The prelude contains common traits and macros.
-use stylus_sdk::{alloy_primitives::U256, prelude::*};
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, U256},
+    call::{self, Call},
+    evm, msg,
+    prelude::*,
+    types::AddressVM,
+};
 
 // Define some persistent storage using the Solidity ABI.
 // `Counter` will be the entrypoint.
@@ -39,18 +49,50 @@ sol_storage! {
     #[entrypoint]
     pub struct Counter {
         uint256 number;
+        bool entered;
     }
 }
 
 sol! {
+    #[derive(AbiType)]
     struct MyStruct {
         uint256 myNum;
     }
+
+    error Reentrancy();
 }
 
 sol_interface! {
     interface IMyInterface {
         function doThing() returns (MyStruct);
+        function echoThing(MyStruct thing) view returns (MyStruct);
+        function sumThings(MyStruct[] things) view returns (uint256);
+        function depositThing(MyStruct thing) payable returns (uint256);
+    }
+}
+
+/// Warms `target` and returns the gas left, so a call to `target` made right after costs
+/// `call_overhead` whether or not the transaction accessed it before (EIP-2929).
+fn warm_gas_left(target: Address) -> u64 {
+    let _ = target.codehash();
+    evm::gas_left()
+}
+
+/// Revert data for a failed call to `target` capped at `gas` and costing `overhead`, made when
+/// `gas_before` gas was left.
+fn capped_call_error(
+    err: call::Error,
+    target: Address,
+    gas: u64,
+    overhead: u64,
+    gas_before: u64,
+) -> Vec<u8> {
+    match err {
+        call::Error::Revert(data) => {
+            CalleeError::decode_capped(&data, gas, overhead, gas_before, evm::gas_left())
+                .reraise(target)
+        }
+        err => err.into(),
     }
 }
 
@@ -62,24 +104,179 @@ impl Counter {
         self.number.get()
     }
 
+    /// Gets the number from storage together with its double, as multiple return values.
+    /// Reverts with `Panic(0x11)` like Solidity when the double overflows.
+    pub fn number_and_double(&self) -> Result<(U256, U256), Vec<u8>> {
+        let number = self.number.get();
+        let double = number
+            .checked_mul(U256::from(2))
+            .ok_or_else(|| Panic::from(PanicKind::UnderOverflow).abi_encode())?;
+        Ok((number, double))
+    }
+
+    /// Calls `doThing` on `target`, stores the `myNum` it returns and passes the struct on.
+    /// A failed call reverts with the callee's revert data.
+    pub fn call_do_thing(&mut self, target: Address) -> Result<MyStruct, Vec<u8>> {
+        self.not_entered()?;
+        let result = IMyInterface::new(target).do_thing(Call::new_in(self))?;
+        self.number.set(result.myNum);
+        Ok(result)
+    }
+
+    /// Like `call_do_thing`, but reports a failed call instead of reverting. `revertData` is what
+    /// the callee reverted with, which may be empty. When the callee returned data that isn't a
+    /// `MyStruct` it is `CalleeReturnedMalformedData(target)` instead, so the two can be told
+    /// apart unless the callee itself reverts with that error. Only `Reentrancy` reverts.
+    pub fn try_do_thing(&mut self, target: Address) -> Result<(bool, MyStruct, Bytes), Vec<u8>> {
+        self.not_entered()?;
+        let report = match IMyInterface::new(target).do_thing(Call::new_in(self)) {
+            Ok(result) => {
+                self.number.set(result.myNum);
+                (true, result, Vec::new().into())
+            }
+            Err(call::Error::Revert(data)) => (false, MyStruct { myNum: U256::ZERO }, data.into()),
+            Err(call::Error::AbiDecodingFailed(_)) => {
+                let data = CalleeReturnedMalformedData { target }.abi_encode();
+                (false, MyStruct { myNum: U256::ZERO }, data.into())
+            }
+        };
+        Ok(report)
+    }
+
+    /// Like `call_do_thing`, but re-raises a revert of the callee as one of this contract's own
+    /// errors, such as `CalleePanicked(target, code)`.
+    pub fn call_do_thing_typed(&mut self, target: Address) -> Result<MyStruct, Vec<u8>> {
+        self.not_entered()?;
+        let result = IMyInterface::new(target)
+            .do_thing(Call::new_in(self))
+            .map_err(|err| match err {
+                call::Error::Revert(data) => CalleeError::decode(&data).reraise(target),
+                err => err.into(),
+            })?;
+        self.number.set(result.myNum);
+        Ok(result)
+    }
+
+    /// Calls `doThing` on `target` with at most `gas` gas. Running out of it reverts with
+    /// `CalleeOutOfGas(target, forwarded)` instead of the empty revert data the callee leaves,
+    /// where `forwarded` is `gas` unless less than that was left to forward.
+    pub fn call_do_thing_with_gas(
+        &mut self,
+        target: Address,
+        gas: u64,
+    ) -> Result<MyStruct, Vec<u8>> {
+        self.not_entered()?;
+        let overhead = call_overhead(U256::ZERO);
+        let gas_before = warm_gas_left(target);
+        let result = IMyInterface::new(target)
+            .do_thing(Call::new_in(self).gas(gas))
+            .map_err(|err| capped_call_error(err, target, gas, overhead, gas_before))?;
+        self.number.set(result.myNum);
+        Ok(result)
+    }
+
+    /// Forwards the value sent with this call to `depositThing` on `target`, with at most `gas`
+    /// gas, and stores the amount the provider reports it received.
+    #[payable]
+    pub fn call_deposit_thing_with_gas(
+        &mut self,
+        target: Address,
+        thing: MyStruct,
+        gas: u64,
+    ) -> Result<U256, Vec<u8>> {
+        self.not_entered()?;
+        let overhead = call_overhead(msg::value());
+        let gas_before = warm_gas_left(target);
+        let context = Call::new_in(self).gas(gas).value(msg::value());
+        let received = IMyInterface::new(target)
+            .deposit_thing(context, thing)
+            .map_err(|err| capped_call_error(err, target, gas, overhead, gas_before))?;
+        self.number.set(received);
+        Ok(received)
+    }
+
+    /// Passes `thing` to `echoThing` on `target` in a static call and returns what comes back.
+    /// A static call can't write storage, so `&self` is enough of a context even with the
+    /// `reentrant` feature, and the method stays `view`.
+    pub fn call_echo_thing(&self, target: Address, thing: MyStruct) -> Result<MyStruct, Vec<u8>> {
+        Ok(IMyInterface::new(target).echo_thing(self, thing)?)
+    }
+
+    /// Passes `things` to `sumThings` on `target` in a static call and returns the sum.
+    pub fn call_sum_things(&self, target: Address, things: Vec<MyStruct>) -> Result<U256, Vec<u8>> {
+        Ok(IMyInterface::new(target).sum_things(self, things)?)
+    }
+
+    /// Forwards the value sent with this call to `depositThing` on `target`, storing the amount
+    /// the provider reports it received.
+    #[payable]
+    pub fn call_deposit_thing(
+        &mut self,
+        target: Address,
+        thing: MyStruct,
+    ) -> Result<U256, Vec<u8>> {
+        self.not_entered()?;
+        let context = Call::new_in(self).value(msg::value());
+        let received = IMyInterface::new(target).deposit_thing(context, thing)?;
+        self.number.set(received);
+        Ok(received)
+    }
+
     /// Sets a number in storage to a user-specified value.
-    pub fn set_number(&mut self, new_number: U256) {
+    pub fn set_number(&mut self, new_number: U256) -> Result<(), Vec<u8>> {
+        self.not_entered()?;
         self.number.set(new_number);
+        Ok(())
     }
 
     /// Sets a number in storage to a user-specified value.
-    pub fn mul_number(&mut self, new_number: U256) {
+    pub fn mul_number(&mut self, new_number: U256) -> Result<(), Vec<u8>> {
+        self.not_entered()?;
         self.number.set(new_number * self.number.get());
+        Ok(())
     }
 
     /// Sets a number in storage to a user-specified value.
-    pub fn add_number(&mut self, new_number: U256) {
+    pub fn add_number(&mut self, new_number: U256) -> Result<(), Vec<u8>> {
+        self.not_entered()?;
         self.number.set(new_number + self.number.get());
+        Ok(())
     }
 
     /// Increments `number` and updates its value in storage.
-    pub fn increment(&mut self) {
+    pub fn increment(&mut self) -> Result<(), Vec<u8>> {
         let number = self.number.get();
-        self.set_number(number + U256::from(1));
+        self.set_number(number + U256::from(1))
+    }
+
+    /// Increments `number`, calls `doThing` on `target` and returns the number afterwards. The
+    /// increment is flushed to storage before the call and `number` is read again after it, so
+    /// the callee sees the new value and a callee re-entering `set_number` is seen here.
+    pub fn increment_and_call(&mut self, target: Address) -> Result<U256, Vec<u8>> {
+        self.increment()?;
+        IMyInterface::new(target).do_thing(Call::new_in(self))?;
+        Ok(self.number.get())
+    }
+
+    /// Like `increment_and_call`, but a callee re-entering any entrypoint that writes `number`
+    /// reverts with `Reentrancy`. The views, `callEchoThing` and `callSumThings` stay callable.
+    pub fn guarded_increment_and_call(&mut self, target: Address) -> Result<U256, Vec<u8>> {
+        self.increment()?;
+        self.entered.set(true);
+        let result = IMyInterface::new(target).do_thing(Call::new_in(self));
+        self.entered.set(false);
+        result?;
+        Ok(self.number.get())
+    }
+}
+
+impl Counter {
+    /// Reverts with `Reentrancy` during a `guarded_increment_and_call`. Every entrypoint that
+    /// writes `number` checks it first, directly or through `set_number`.
+    fn not_entered(&self) -> Result<(), Vec<u8>> {
+        if self.entered.get() {
+            return Err(Reentrancy {}.abi_encode());
+        }
+        Ok(())
     }
 }
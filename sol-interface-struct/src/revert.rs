@@ -0,0 +1,125 @@
+//! Decoding of the revert data an `IMyInterface` callee fails with.
+//!
+//! [`CalleeError::decode`] recognizes the callee's custom errors along with Solidity's
+//! `Error(string)` and `Panic(uint256)`, and [`CalleeError::reraise`] encodes the result as one
+//! of this contract's own errors, so callers see which kind of failure happened downstream.
+
+use alloc::{string::String, vec::Vec};
+use alloy_sol_types::{sol, Panic, Revert, SolError};
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+sol! {
+    /// Custom error of the callee, as declared by `sol-interface-provider`.
+    error DoThingFailed(uint256 myNum);
+
+    /// The callee reverted with `DoThingFailed`.
+    error CalleeDoThingFailed(address target, uint256 myNum);
+    /// The callee reverted with `Error(string)`.
+    error CalleeReverted(address target, string reason);
+    /// The callee reverted with `Panic(uint256)`.
+    error CalleePanicked(address target, uint256 code);
+    /// The callee ran out of the `gas` it was given.
+    error CalleeOutOfGas(address target, uint64 gas);
+    /// The callee reverted with data that matches none of the above.
+    error CalleeFailed(address target, bytes revertData);
+    /// The callee returned data that doesn't decode as the declared return type.
+    error CalleeReturnedMalformedData(address target);
+}
+
+/// Gas a call to an account already accessed in the transaction costs the caller besides the
+/// gas it forwards (EIP-2929).
+pub const WARM_CALL_COST: u64 = 100;
+
+/// Extra gas a call that carries value costs the caller. The callee's 2300 gas stipend comes on
+/// top of what is forwarded and isn't paid by the caller.
+pub const VALUE_TRANSFER_COST: u64 = 9_000;
+
+/// Gas a call to a warm account sending `value` costs the caller besides the gas it forwards.
+pub fn call_overhead(value: U256) -> u64 {
+    if value.is_zero() {
+        WARM_CALL_COST
+    } else {
+        WARM_CALL_COST + VALUE_TRANSFER_COST
+    }
+}
+
+/// Gas by which a call may fall short of using up what it was given and still count as out of
+/// gas. [`call_overhead`] is an estimate, and a few hundred gas of drift in what the call
+/// really costs, from a repricing or the rounding of ink into gas, would otherwise flip an empty
+/// revert between out of gas and a plain failure. Callees reverting with less than this left over
+/// are taken to have run out, which also means every empty revert under a cap this small does.
+pub const OUT_OF_GAS_MARGIN: u64 = 500;
+
+/// Why a call to `target` reverted.
+pub enum CalleeError {
+    DoThingFailed(DoThingFailed),
+    Revert(String),
+    Panic(U256),
+    OutOfGas(u64),
+    Unknown(Vec<u8>),
+}
+
+impl CalleeError {
+    /// Decodes the revert data of a failed call. Data that starts with a known selector but
+    /// doesn't decode is kept as [`CalleeError::Unknown`].
+    pub fn decode(data: &[u8]) -> Self {
+        if let Ok(err) = DoThingFailed::abi_decode(data, true) {
+            return Self::DoThingFailed(err);
+        }
+        if let Ok(err) = Revert::abi_decode(data, true) {
+            return Self::Revert(err.reason);
+        }
+        if let Ok(err) = Panic::abi_decode(data, true) {
+            return Self::Panic(err.code);
+        }
+        Self::Unknown(data.to_vec())
+    }
+
+    /// Like [`CalleeError::decode`], for a call capped at `gas` that costs `overhead` on top of
+    /// the gas it forwards, made with `gas_before` gas left and returning with `gas_after`.
+    ///
+    /// Running out of gas leaves no revert data, so an empty revert is
+    /// [`CalleeError::OutOfGas`] when the callee used up everything it was given, and
+    /// [`CalleeError::Unknown`] when it left gas over. The callee gets `gas`, or all but a 64th
+    /// of the gas left after the overhead if that is less (EIP-150). Using up a cap shows in the
+    /// drop of the caller's gas past `overhead + gas`, and using up the 63/64 leaves the caller
+    /// with no more than the 64th it kept. Both are allowed [`OUT_OF_GAS_MARGIN`] of slack.
+    pub fn decode_capped(
+        data: &[u8],
+        gas: u64,
+        overhead: u64,
+        gas_before: u64,
+        gas_after: u64,
+    ) -> Self {
+        let available = gas_before.saturating_sub(overhead);
+        let forwarded = gas.min(available - available / 64);
+        let used_cap = gas_before
+            .saturating_sub(gas_after)
+            .saturating_add(OUT_OF_GAS_MARGIN)
+            >= overhead.saturating_add(gas);
+        let used_all = gas_after <= available / 64 + OUT_OF_GAS_MARGIN;
+        if data.is_empty() && (used_cap || used_all) {
+            return Self::OutOfGas(forwarded);
+        }
+        Self::decode(data)
+    }
+
+    /// Encodes the error as this contract's own revert data for a call to `target`.
+    pub fn reraise(self, target: Address) -> Vec<u8> {
+        match self {
+            Self::DoThingFailed(err) => CalleeDoThingFailed {
+                target,
+                myNum: err.myNum,
+            }
+            .abi_encode(),
+            Self::Revert(reason) => CalleeReverted { target, reason }.abi_encode(),
+            Self::Panic(code) => CalleePanicked { target, code }.abi_encode(),
+            Self::OutOfGas(gas) => CalleeOutOfGas { target, gas }.abi_encode(),
+            Self::Unknown(data) => CalleeFailed {
+                target,
+                revertData: data.into(),
+            }
+            .abi_encode(),
+        }
+    }
+}
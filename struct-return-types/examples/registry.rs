@@ -0,0 +1,175 @@
+//! Example on how a registry of structs in a deployed `struct-return-types` program is managed.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It creates, updates and deletes `Foo` entries of the `mapping(uint256 => Foo)` registry,
+//! decodes the struct carried by each event, pages through the entries with `listFoos` and
+//! checks that deleting an entry zeroes the storage slot it was kept in.
+
+use ethers::{
+    abi::{encode, Token},
+    contract::parse_log,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, H256, U256},
+    utils::keccak256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+/// Slot of `foo_registry`, after `number`, `name`, `data`, `values`, `foos` and the two slots of
+/// `record`.
+const FOO_REGISTRY_SLOT: u64 = 7;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct Foo { uint256 value; }
+            event FooCreated(uint256 indexed id, Foo foo)
+            event FooUpdated(uint256 indexed id, Foo foo)
+            event FooDeleted(uint256 indexed id)
+            error UnknownFoo(uint256 id)
+            function createFoo(Foo foo) external returns (uint256)
+            function updateFoo(uint256 id, Foo foo) external
+            function deleteFoo(uint256 id) external
+            function getFoo(uint256 id) external view returns (Foo)
+            function listFoos(uint256 offset, uint256 limit) external view returns (Foo[])
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider.clone(),
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+    let start = counter
+        .list_foos(U256::zero(), U256::MAX)
+        .call()
+        .await?
+        .len();
+
+    // Create three entries and read the struct back out of each FooCreated event.
+    let mut ids = vec![];
+    for value in [10u64, 20, 30] {
+        let foo = Foo {
+            value: U256::from(value),
+        };
+        let receipt = counter
+            .create_foo(foo.clone())
+            .send()
+            .await?
+            .await?
+            .ok_or_else(|| eyre!("No receipt"))?;
+        let created: FooCreatedFilter = parse_log(receipt.logs[0].clone())?;
+        assert_eq!(foo, created.foo);
+        assert_eq!(foo, counter.get_foo(created.id).call().await?);
+        ids.push(created.id);
+    }
+    println!("Created foos {:?}", ids);
+
+    let receipt = counter
+        .update_foo(
+            ids[1],
+            Foo {
+                value: U256::from(21),
+            },
+        )
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let updated: FooUpdatedFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!((ids[1], U256::from(21)), (updated.id, updated.foo.value));
+    println!("Successfully updated foo {}", ids[1]);
+
+    // Pages of two over the entries created above
+    let page = counter
+        .list_foos(U256::from(start), U256::from(2))
+        .call()
+        .await?;
+    assert_eq!(vec![U256::from(10), U256::from(21)], values(&page));
+    let page = counter
+        .list_foos(U256::from(start + 2), U256::from(2))
+        .call()
+        .await?;
+    assert_eq!(vec![U256::from(30)], values(&page));
+    assert!(counter
+        .list_foos(U256::from(start + 3), U256::from(2))
+        .call()
+        .await?
+        .is_empty());
+    println!("Successfully paged through foos");
+
+    // Deleting the first entry zeroes its slot and moves the last entry into its place.
+    let slot = registry_slot(ids[0]);
+    assert_eq!(
+        H256::from_low_u64_be(10),
+        provider.get_storage_at(address, slot, None).await?
+    );
+    let receipt = counter
+        .delete_foo(ids[0])
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let deleted: FooDeletedFilter = parse_log(receipt.logs[0].clone())?;
+    assert_eq!(ids[0], deleted.id);
+    assert_eq!(
+        H256::zero(),
+        provider.get_storage_at(address, slot, None).await?
+    );
+    assert!(counter.get_foo(ids[0]).call().await.is_err());
+    assert!(counter.delete_foo(ids[0]).call().await.is_err());
+    let page = counter
+        .list_foos(U256::from(start), U256::MAX)
+        .call()
+        .await?;
+    assert_eq!(vec![U256::from(30), U256::from(21)], values(&page));
+    println!("Successfully deleted foo {}", ids[0]);
+
+    Ok(())
+}
+
+/// The slot of `foo_registry[id].value`: `keccak256(abi.encode(id, slot))`.
+fn registry_slot(id: U256) -> H256 {
+    let data = encode(&[Token::Uint(id), Token::Uint(U256::from(FOO_REGISTRY_SLOT))]);
+    H256::from(keccak256(data))
+}
+
+/// The `value` of each struct in a page.
+fn values(page: &[Foo]) -> Vec<U256> {
+    page.iter().map(|foo| foo.value).collect()
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
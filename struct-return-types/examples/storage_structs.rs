@@ -0,0 +1,90 @@
+//! Example on how storage structs of a deployed `struct-return-types` program are read and written.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It persists `Foo` and `Record` arguments, which the program stores field by field through the
+//! generated `store` conversions, and checks the structs `load` rebuilds from storage.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, U256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct Foo { uint256 value; }
+            struct Record { uint256 value; address owner; }
+            function number() external view returns (Foo)
+            function setFoo(Foo foo) external
+            function setNumber(uint256 number) external
+            function getRecord() external view returns (Record)
+            function setRecord(Record record) external
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // FooStorage, which shares its slot with the plain number setters
+    let foo = Foo {
+        value: U256::from(5),
+    };
+    counter.set_foo(foo.clone()).send().await?.await?;
+    assert_eq!(foo, counter.number().call().await?);
+    counter.set_number(U256::from(6)).send().await?.await?;
+    assert_eq!(U256::from(6), counter.number().call().await?.value);
+    println!("Successfully stored and loaded Foo");
+
+    // RecordStorage, whose address member takes the slot after the uint256
+    let record = Record {
+        value: U256::MAX,
+        owner: wallet.address(),
+    };
+    counter.set_record(record.clone()).send().await?.await?;
+    let stored = counter.get_record().call().await?;
+    println!("Counter record = {:?}", stored);
+    assert_eq!(record, stored);
+    println!("Successfully stored and loaded Record");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
@@ -0,0 +1,123 @@
+//! Example on how events and custom errors carrying a struct are encoded by a deployed `struct-return-types` program.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It compares the raw log of an update and the raw revert data of an invalid update against the
+//! canonical encoding, where the `Foo` member is the tuple `(uint256)`, before decoding both
+//! through the ABI.
+
+use ethers::{
+    abi::{encode, Token},
+    contract::parse_log,
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, H256, U256},
+    utils::{id, keccak256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct Foo { uint256 value; }
+            event FooUpdated(uint256 indexed id, Foo foo)
+            error UnknownFoo(uint256 id)
+            error InvalidFoo(Foo foo)
+            function createFoo(Foo foo) external returns (uint256)
+            function updateFoo(uint256 id, Foo foo) external
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    let foo = Foo {
+        value: U256::from(1),
+    };
+    let foo_id = counter.create_foo(foo.clone()).call().await?;
+    counter.create_foo(foo).send().await?.await?;
+
+    // FooUpdated: the id is a topic and the struct is ABI encoded as a tuple in the data.
+    let updated = Foo {
+        value: U256::from(0xabcd),
+    };
+    let receipt = counter
+        .update_foo(foo_id, updated.clone())
+        .send()
+        .await?
+        .await?
+        .ok_or_else(|| eyre!("No receipt"))?;
+    let log = receipt.logs[0].clone();
+    assert_eq!(
+        H256::from(keccak256("FooUpdated(uint256,(uint256))")),
+        log.topics[0]
+    );
+    assert_eq!(H256::from_uint(&foo_id), log.topics[1]);
+    assert_eq!(
+        encode(&[Token::Tuple(vec![Token::Uint(updated.value)])]),
+        log.data.to_vec()
+    );
+    let event: FooUpdatedFilter = parse_log(log)?;
+    assert_eq!((foo_id, updated), (event.id, event.foo));
+    println!("FooUpdated log data is the canonical tuple encoding");
+
+    // InvalidFoo: the selector of `InvalidFoo((uint256))` followed by the encoded tuple.
+    let invalid = Foo {
+        value: U256::zero(),
+    };
+    let err = counter
+        .update_foo(foo_id, invalid.clone())
+        .call()
+        .await
+        .expect_err("updating to a zero value should revert");
+    let data = err
+        .as_revert()
+        .ok_or_else(|| eyre!("updateFoo failed without revert data: {err}"))?;
+    let mut expected = id("InvalidFoo((uint256))").to_vec();
+    expected.extend(encode(&[Token::Tuple(vec![Token::Uint(invalid.value)])]));
+    assert_eq!(expected, data.to_vec());
+    match err.decode_contract_revert::<CounterErrors>() {
+        Some(CounterErrors::InvalidFoo(InvalidFoo { foo })) => assert_eq!(invalid, foo),
+        other => return Err(eyre!("expected InvalidFoo, got {other:?}")),
+    }
+    println!("InvalidFoo revert data is the canonical tuple encoding");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
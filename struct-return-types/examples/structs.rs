@@ -0,0 +1,139 @@
+//! Example on how nested and dynamic structs round trip through a deployed `struct-return-types` program.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It passes `Foo` as an argument, builds up a `Foo[]` in storage, then sets and reads back an
+//! `Outer` struct holding a nested `Foo` alongside `string`, `bytes`, `uint256[]` and `Foo[]`
+//! members, and echoes one without touching storage.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes, U256},
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct Foo { uint256 value; }
+            struct Outer { Foo foo; string name; bytes data; uint256[] values; Foo[] foos; }
+            function number() external view returns (Foo)
+            function setFoo(Foo foo) external
+            function pushFoo(Foo foo) external
+            function getFoos() external view returns (Foo[])
+            function getNested() external view returns (Outer)
+            function setNested(Outer outer) external
+            function echoNested(Outer outer) external pure returns (Outer)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    // Foo as an argument
+    let foo = Foo {
+        value: U256::from(11),
+    };
+    counter.set_foo(foo.clone()).send().await?.await?;
+    assert_eq!(foo, counter.number().call().await?);
+    println!("Successfully set a struct argument");
+
+    // Foo[] built up one push at a time
+    let start = counter.get_foos().call().await?;
+    counter
+        .push_foo(Foo {
+            value: U256::from(1),
+        })
+        .send()
+        .await?
+        .await?;
+    counter
+        .push_foo(Foo {
+            value: U256::from(2),
+        })
+        .send()
+        .await?
+        .await?;
+    let foos = counter.get_foos().call().await?;
+    assert_eq!(start.len() + 2, foos.len());
+    assert_eq!(U256::from(2), foos[foos.len() - 1].value);
+    println!("Counter foos = {:?}", foos);
+
+    // Outer, whose dynamic members are encoded after the static head
+    let outer = Outer {
+        foo: Foo {
+            value: U256::from(42),
+        },
+        name: "a name longer than thirty-one bytes so it spans slots".to_string(),
+        data: Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]),
+        values: vec![U256::zero(), U256::one(), U256::MAX],
+        foos: vec![
+            Foo {
+                value: U256::from(7),
+            },
+            Foo {
+                value: U256::from(8),
+            },
+        ],
+    };
+    assert_eq!(outer, counter.echo_nested(outer.clone()).call().await?);
+    println!("Successfully echoed a nested struct");
+
+    counter.set_nested(outer.clone()).send().await?.await?;
+    let nested = counter.get_nested().call().await?;
+    println!("Counter nested = {:?}", nested);
+    assert_eq!(outer, nested);
+    assert_eq!(outer.foo, counter.number().call().await?);
+
+    // Shorter arrays replace the stored ones rather than overwriting a prefix.
+    let shorter = Outer {
+        name: String::new(),
+        data: Bytes::new(),
+        values: vec![],
+        foos: vec![],
+        ..outer
+    };
+    counter.set_nested(shorter.clone()).send().await?.await?;
+    assert_eq!(shorter, counter.get_nested().call().await?);
+    println!("Successfully round tripped a nested struct through storage");
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
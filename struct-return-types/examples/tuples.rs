@@ -0,0 +1,84 @@
+//! Example on how multiple return values of a deployed `struct-return-types` program are decoded.
+//! This example uses ethers-rs to instantiate the program using a Solidity ABI.
+//! It decodes the `(Foo, uint256)` that `numberWithRaw` returns, and the nested
+//! `((Foo, uint256), bool)` of `numberNested`.
+
+use ethers::{
+    middleware::SignerMiddleware,
+    prelude::abigen,
+    providers::{Http, Middleware, Provider},
+    signers::{LocalWallet, Signer},
+    types::Address,
+    U256,
+};
+use eyre::eyre;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Your private key file path.
+const PRIV_KEY_PATH: &str = "PRIV_KEY_PATH";
+
+/// Stylus RPC endpoint url.
+const RPC_URL: &str = "RPC_URL";
+
+/// Deployed pragram address.
+const STYLUS_PROGRAM_ADDRESS: &str = "ADDR";
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let priv_key_path =
+        std::env::var(PRIV_KEY_PATH).map_err(|_| eyre!("No {} env var set", PRIV_KEY_PATH))?;
+    let rpc_url = std::env::var(RPC_URL).map_err(|_| eyre!("No {} env var set", RPC_URL))?;
+    let program_address = std::env::var(STYLUS_PROGRAM_ADDRESS)
+        .map_err(|_| eyre!("No {} env var set", STYLUS_PROGRAM_ADDRESS))?;
+    abigen!(
+        Counter,
+        r#"[
+            struct Foo { uint256 value; }
+            function setNumber(uint256 number) external
+            function numberWithRaw() external view returns (Foo, uint256)
+            function numberNested() external view returns ((Foo, uint256), bool)
+        ]"#
+    );
+
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let address: Address = program_address.parse()?;
+
+    let privkey = read_secret_from_file(&priv_key_path)?;
+    let wallet = LocalWallet::from_str(&privkey)?;
+    let chain_id = provider.get_chainid().await?.as_u64();
+    let client = Arc::new(SignerMiddleware::new(
+        provider,
+        wallet.clone().with_chain_id(chain_id),
+    ));
+
+    let counter = Counter::new(address, client);
+
+    let pending = counter.set_number(U256::from(3));
+    if let Some(receipt) = pending.send().await?.await? {
+        println!("Receipt = {:?}", receipt);
+    }
+    println!("Successfully set counter via a tx");
+
+    let (foo, raw) = counter.number_with_raw().call().await?;
+    println!("Counter number = {:?}, raw = {:?}", foo, raw);
+    assert_eq!(U256::from(3), foo.value);
+    assert_eq!(U256::from(3), raw);
+
+    let ((foo, raw), zero) = counter.number_nested().call().await?;
+    println!("Counter nested = {:?}", ((&foo, raw), zero));
+    assert_eq!(U256::from(3), foo.value);
+    assert_eq!(U256::from(3), raw);
+    assert!(!zero);
+
+    Ok(())
+}
+
+fn read_secret_from_file(fpath: &str) -> eyre::Result<String> {
+    let f = std::fs::File::open(fpath)?;
+    let mut buf_reader = BufReader::new(f);
+    let mut secret = String::new();
+    buf_reader.read_line(&mut secret)?;
+    Ok(secret.trim().to_string())
+}
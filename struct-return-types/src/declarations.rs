@@ -0,0 +1,62 @@
+//! Solidity declarations of the events and errors in this crate's `sol!` block.
+//!
+//! `export-abi` only prints the `ICounter` interface, which has no events or errors. The
+//! block is declared with `sol_with_declarations!`, which passes the same tokens it hands to
+//! `sol!` to [`events_and_errors`], so the printed declarations can't drift from the types the
+//! program encodes.
+
+/// Renders the `event` and `error` items of a stringified `sol!` body as Solidity, one per line
+/// with a blank line between the events and the errors. Structs declared in the body are
+/// qualified with `ICounter.`, where `export-abi` declares them.
+pub fn events_and_errors(body: &str) -> String {
+    let words: Vec<&str> = body.split_whitespace().collect();
+    let structs: Vec<&str> = words
+        .windows(2)
+        .filter(|pair| pair[0] == "struct")
+        .map(|pair| pair[1].trim_end_matches('{'))
+        .collect();
+
+    let mut lines = Vec::new();
+    let mut last_kind = None;
+    // Struct fields end in `;` too, so only keep what follows the last `}` of each item.
+    for item in body.split(';') {
+        let item = item.rsplit('}').next().unwrap_or_default().trim();
+        let Some((kind, rest)) = item.split_once(char::is_whitespace) else {
+            continue;
+        };
+        if kind != "event" && kind != "error" {
+            continue;
+        }
+        let (name, params) = rest
+            .split_once('(')
+            .unwrap_or_else(|| panic!("unsupported {kind} {rest}"));
+        let params: Vec<String> = params
+            .trim_end()
+            .trim_end_matches(')')
+            .split(',')
+            .filter(|param| !param.trim().is_empty())
+            .map(|param| {
+                // `stringify!` spaces tokens freely, as in `Foo [] foos`.
+                let param = param
+                    .replace(" [", "[")
+                    .replace("[ ", "[")
+                    .replace(" ]", "]");
+                let mut words = param.split_whitespace();
+                let ty = words.next().unwrap_or_default();
+                let base = ty.split('[').next().unwrap_or_default();
+                let ty = if structs.contains(&base) {
+                    format!("ICounter.{ty}")
+                } else {
+                    ty.to_string()
+                };
+                words.fold(ty, |param, word| param + " " + word)
+            })
+            .collect();
+        if last_kind.is_some_and(|last| last != kind) {
+            lines.push(String::new());
+        }
+        last_kind = Some(kind);
+        lines.push(format!("{kind} {}({});", name.trim(), params.join(", ")));
+    }
+    lines.join("\n")
+}
@@ -29,24 +29,130 @@ extern crate alloc;
 #[global_allocator]
 static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
 
-use alloy_sol_types::sol;
+#[cfg(feature = "export-abi")]
+pub mod declarations;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolError};
 /// Import items from the SDK. The prelude contains common traits and macros.
-use stylus_sdk::{alloy_primitives::U256, prelude::*};
+use stylus_sdk::{alloy_primitives::U256, evm, prelude::*, storage::Erase};
+
+/// Declares a `sol_storage!` struct mirroring the `sol!` struct `$abi`, and implements `load` and
+/// `store` between the two from the declared fields. `load` builds and `store` destructures the
+/// ABI struct by naming every declared field, so a field on only one side fails to compile.
+macro_rules! sol_storage_for {
+    ($(#[$($attr:tt)*])* pub struct $storage:ident => $abi:ident { $($ty:ident $field:ident;)* }) => {
+        sol_storage! {
+            $(#[$($attr)*])*
+            pub struct $storage { $($ty $field;)* }
+        }
+
+        impl $storage {
+            /// Reads every field into the ABI struct.
+            pub fn load(&self) -> $abi {
+                $abi {
+                    $($field: self.$field.get(),)*
+                }
+            }
+
+            /// Writes every field of the ABI struct.
+            pub fn store(&mut self, value: &$abi) {
+                let $abi { $($field),* } = value;
+                $(self.$field.set(*$field);)*
+            }
+        }
+    };
+}
 
 // Define some persistent storage using the Solidity ABI.
 // `Counter` will be the entrypoint.
 sol_storage! {
     #[entrypoint]
     pub struct Counter {
-        uint256 number;
+        FooStorage number;
+        string name;
+        bytes data;
+        uint256[] values;
+        uint256[] foos;
+        RecordStorage record;
+        mapping(uint256 => FooStorage) foo_registry;
+        uint256[] foo_ids;
+        mapping(uint256 => uint256) foo_positions;
+        uint256 next_foo_id;
+    }
+}
+
+sol_storage_for! {
+    #[derive(Erase)]
+    pub struct FooStorage => Foo {
+        uint256 value;
     }
 }
 
-sol! {
+sol_storage_for! {
+    pub struct RecordStorage => Record {
+        uint256 value;
+        address owner;
+    }
+}
+
+/// Expands the items with `sol!`, and under `export-abi` adds `events_and_errors()` with the
+/// Solidity declarations of their events and errors.
+macro_rules! sol_with_declarations {
+    ($($items:tt)*) => {
+        sol! { $($items)* }
+
+        /// Solidity declarations of the events and errors above, which `export-abi` leaves out of
+        /// the generated `ICounter` interface.
+        #[cfg(feature = "export-abi")]
+        pub fn events_and_errors() -> String {
+            declarations::events_and_errors(stringify!($($items)*))
+        }
+    };
+}
+
+sol_with_declarations! {
     #[derive(AbiType)]
     struct Foo {
         uint256 value;
     }
+
+    #[derive(AbiType)]
+    struct Outer {
+        Foo foo;
+        string name;
+        bytes data;
+        uint256[] values;
+        Foo[] foos;
+    }
+
+    #[derive(AbiType)]
+    struct Record {
+        uint256 value;
+        address owner;
+    }
+
+    event FooCreated(uint256 indexed id, Foo foo);
+    event FooUpdated(uint256 indexed id, Foo foo);
+    event FooDeleted(uint256 indexed id);
+
+    error UnknownFoo(uint256 id);
+    error InvalidFoo(Foo foo);
+}
+
+/// Checks a struct before it goes in the registry, which only holds nonzero values. This is a
+/// rule of the registry rather than of its storage: `foo_positions` tracks which ids exist, so a
+/// zero value would be stored and read back like any other.
+fn validate_foo(foo: &Foo) -> Result<(), Vec<u8>> {
+    if foo.value.is_zero() {
+        return Err(InvalidFoo { foo: foo.clone() }.abi_encode());
+    }
+    Ok(())
+}
+
+/// Revert data for an id that is not in the registry.
+fn unknown_foo(id: U256) -> Vec<u8> {
+    UnknownFoo { id }.abi_encode()
 }
 
 /// Declare that `Counter` is a contract with the following external methods.
@@ -54,29 +160,167 @@ sol! {
 impl Counter {
     /// Gets the number from storage.
     pub fn number(&self) -> Foo {
-        Foo {
-            value: self.number.get(),
+        self.number.load()
+    }
+
+    /// Gets the number from storage both as a struct and as a plain value.
+    pub fn number_with_raw(&self) -> (Foo, U256) {
+        let value = self.number.value.get();
+        (Foo { value }, value)
+    }
+
+    /// Gets the number as a nested tuple containing a struct, plus whether it is zero.
+    pub fn number_nested(&self) -> ((Foo, U256), bool) {
+        let value = self.number.value.get();
+        ((Foo { value }, value), value.is_zero())
+    }
+
+    /// Sets the number in storage from a struct argument.
+    pub fn set_foo(&mut self, foo: Foo) {
+        self.number.store(&foo);
+    }
+
+    /// Appends a struct to the stored list.
+    pub fn push_foo(&mut self, foo: Foo) {
+        self.foos.push(foo.value);
+    }
+
+    /// Gets the stored list as an array of structs.
+    pub fn get_foos(&self) -> Vec<Foo> {
+        (0..self.foos.len())
+            .map(|i| Foo {
+                value: self.foos.get(i).unwrap(),
+            })
+            .collect()
+    }
+
+    /// Gets a struct with nested and dynamic members built from storage.
+    pub fn get_nested(&self) -> Outer {
+        Outer {
+            foo: self.number(),
+            name: self.name.get_string(),
+            data: self.data.get_bytes().into(),
+            values: (0..self.values.len())
+                .map(|i| self.values.get(i).unwrap())
+                .collect(),
+            foos: self.get_foos(),
+        }
+    }
+
+    /// Replaces everything `get_nested` returns with the members of `outer`.
+    pub fn set_nested(&mut self, outer: Outer) {
+        self.number.store(&outer.foo);
+        self.name.set_str(&outer.name);
+        self.data.set_bytes(&outer.data);
+        self.values.erase();
+        for value in outer.values {
+            self.values.push(value);
+        }
+        self.foos.erase();
+        for foo in outer.foos {
+            self.foos.push(foo.value);
+        }
+    }
+
+    /// Returns its argument, round tripping the head/tail encoding of a dynamic struct.
+    pub fn echo_nested(outer: Outer) -> Outer {
+        outer
+    }
+
+    /// Gets the record from storage.
+    pub fn get_record(&self) -> Record {
+        self.record.load()
+    }
+
+    /// Persists a record in storage.
+    pub fn set_record(&mut self, record: Record) {
+        self.record.store(&record);
+    }
+
+    /// Adds a struct to the registry under the next id, which is returned. Reverts with
+    /// `InvalidFoo` for a zero value.
+    pub fn create_foo(&mut self, foo: Foo) -> Result<U256, Vec<u8>> {
+        validate_foo(&foo)?;
+        let id = self.next_foo_id.get();
+        self.next_foo_id.set(id + U256::from(1));
+        self.foo_registry.setter(id).store(&foo);
+        self.foo_ids.push(id);
+        self.foo_positions
+            .insert(id, U256::from(self.foo_ids.len()));
+        evm::log(FooCreated { id, foo });
+        Ok(id)
+    }
+
+    /// Overwrites the struct stored under `id`. Reverts with `InvalidFoo` for a zero value.
+    pub fn update_foo(&mut self, id: U256, foo: Foo) -> Result<(), Vec<u8>> {
+        if self.foo_positions.get(id).is_zero() {
+            return Err(unknown_foo(id));
+        }
+        validate_foo(&foo)?;
+        self.foo_registry.setter(id).store(&foo);
+        evm::log(FooUpdated { id, foo });
+        Ok(())
+    }
+
+    /// Removes `id` from the registry, zeroing the slots of its struct. The last id takes its
+    /// place in the list, so `listFoos` order is not preserved across deletes.
+    pub fn delete_foo(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        let position = self.foo_positions.get(id);
+        if position.is_zero() {
+            return Err(unknown_foo(id));
+        }
+        let last = self.foo_ids.pop().unwrap();
+        if last != id {
+            let index = position.to::<usize>() - 1;
+            self.foo_ids.setter(index).unwrap().set(last);
+            self.foo_positions.insert(last, position);
+        }
+        self.foo_positions.delete(id);
+        self.foo_registry.delete(id);
+        evm::log(FooDeleted { id });
+        Ok(())
+    }
+
+    /// Gets the struct stored under `id`.
+    pub fn get_foo(&self, id: U256) -> Result<Foo, Vec<u8>> {
+        if self.foo_positions.get(id).is_zero() {
+            return Err(unknown_foo(id));
+        }
+        Ok(self.foo_registry.get(id).load())
+    }
+
+    /// Gets up to `limit` registry structs, starting at the `offset`-th id. Pages past the end
+    /// are empty rather than reverting.
+    pub fn list_foos(&self, offset: U256, limit: U256) -> Vec<Foo> {
+        let len = self.foo_ids.len();
+        if offset >= U256::from(len) {
+            return Vec::new();
         }
+        let start = offset.to::<usize>();
+        let end = start + limit.min(U256::from(len - start)).to::<usize>();
+        (start..end)
+            .map(|i| self.foo_registry.get(self.foo_ids.get(i).unwrap()).load())
+            .collect()
     }
 
     /// Sets a number in storage to a user-specified value.
     pub fn set_number(&mut self, new_number: U256) {
-        self.number.set(new_number);
+        self.number.value.set(new_number);
     }
 
     /// Sets a number in storage to a user-specified value.
     pub fn mul_number(&mut self, new_number: U256) {
-        self.number.set(new_number * self.number.get());
+        self.number.value.set(new_number * self.number.value.get());
     }
 
     /// Sets a number in storage to a user-specified value.
     pub fn add_number(&mut self, new_number: U256) {
-        self.number.set(new_number + self.number.get());
+        self.number.value.set(new_number + self.number.value.get());
     }
 
     /// Increments `number` and updates its value in storage.
     pub fn increment(&mut self) {
-        let number = self.number.get();
-        self.number.set(number + U256::from(1));
+        let number = self.number.value.get();
+        self.number.value.set(number + U256::from(1));
     }
 }
@@ -3,4 +3,6 @@
 #[cfg(feature = "export-abi")]
 fn main() {
     stylus_hello_world::print_abi("MIT-OR-APACHE-2.0", "pragma solidity ^0.8.23;");
+    println!();
+    println!("{}", stylus_hello_world::events_and_errors());
 }